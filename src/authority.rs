@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use super::{
     character_classes::{
         REG_NAME_NOT_PCT_ENCODED,
@@ -6,10 +8,14 @@ use super::{
     codec::{
         decode_element,
         encode_element,
+        encoded_element_len,
+        DecodeBudget,
     },
     context::Context,
     error::Error,
     parse_host_port::parse_host_port,
+    parse_options::ParseOptions,
+    validate_ipv4_address::reject_non_decimal_octets,
     validate_ipv6_address::validate_ipv6_address,
 };
 
@@ -53,16 +59,288 @@ use super::{
 pub struct Authority {
     userinfo: Option<Vec<u8>>,
     host: Vec<u8>,
+    host_is_ipv_future: bool,
     port: Option<u16>,
 }
 
 impl Authority {
+    /// Borrow the userinfo, host name, and port number parts of the
+    /// Authority all at once, which is handy for structured logging.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate rhymuri;
+    /// use rhymuri::Authority;
+    ///
+    /// # fn main() -> Result<(), rhymuri::Error> {
+    /// let authority = Authority::parse("nobody@www.example.com:8080")?;
+    /// let (userinfo, host, port) = authority.components();
+    /// assert_eq!(Some("nobody".as_bytes()), userinfo);
+    /// assert_eq!("www.example.com".as_bytes(), host);
+    /// assert_eq!(Some(8080), port);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use = "why borrow the components if you're not going to use them?"]
+    pub fn components(&self) -> (Option<&[u8]>, &[u8], Option<u16>) {
+        (self.userinfo(), self.host(), self.port())
+    }
+
+    /// Determine the number of characters in the string form of the
+    /// Authority, without actually generating it.  This is useful for
+    /// aligning Authorities in columns, for example.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate rhymuri;
+    /// use rhymuri::Authority;
+    ///
+    /// # fn main() -> Result<(), rhymuri::Error> {
+    /// let authority = Authority::parse("b%40b@[::1]:8080")?;
+    /// assert_eq!("b%40b@[::1]:8080".len(), authority.display_width());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use = "you measured the Authority, so use the measurement"]
+    pub fn display_width(&self) -> usize {
+        let userinfo_width = self.userinfo.as_ref().map_or(0, |userinfo| {
+            encoded_element_len(userinfo, &USER_INFO_NOT_PCT_ENCODED) + 1
+        });
+        let host_width = match std::str::from_utf8(&self.host) {
+            Ok(host)
+                if self.host_is_ipv_future
+                    || validate_ipv6_address(host).is_ok() =>
+            {
+                host.len() + 2
+            },
+            _ => encoded_element_len(&self.host, &REG_NAME_NOT_PCT_ENCODED),
+        };
+        let port_width = self.port.map_or(0, |port| {
+            std::iter::successors(Some(port), |&port| {
+                (port >= 10).then_some(port / 10)
+            })
+            .count()
+                + 1
+        });
+        userinfo_width + host_width + port_width
+    }
+
+    /// Determine whether the Authority is equivalent to the other one,
+    /// comparing the userinfo and port number exactly, but the host name
+    /// without regard to case, since host names are case-insensitive.  IP
+    /// addresses are compared as addresses, so that different ways of
+    /// writing the same IPv6 address (such as `::1` and `0:0::1`) match.  An
+    /// `IPvFuture` address only matches another `IPvFuture` address, with
+    /// the version (such as `v7`) compared without regard to case, but the
+    /// rest of the address compared exactly, since its meaning isn't known.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate rhymuri;
+    /// use rhymuri::Authority;
+    ///
+    /// # fn main() -> Result<(), rhymuri::Error> {
+    /// let authority = Authority::parse("bob@Example.com:80")?;
+    /// let other = Authority::parse("bob@example.COM:80")?;
+    /// assert!(authority.eq_ignore_case(&other));
+    /// let other = Authority::parse("Bob@example.com:80")?;
+    /// assert!(!authority.eq_ignore_case(&other));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use = "you compared the authorities, so check the answer"]
+    pub fn eq_ignore_case(
+        &self,
+        other: &Self,
+    ) -> bool {
+        self.userinfo == other.userinfo
+            && self.port == other.port
+            && self.host_is_ipv_future == other.host_is_ipv_future
+            && match (self.host_as_ip_addr(), other.host_as_ip_addr()) {
+                (Some(addr), Some(other_addr)) => addr == other_addr,
+                _ if self.host_is_ipv_future => {
+                    let version_end = |host: &[u8]| {
+                        host.iter()
+                            .position(|&c| c == b'.')
+                            .map_or(host.len(), |i| i + 1)
+                    };
+                    let (version, address) =
+                        self.host.split_at(version_end(&self.host));
+                    let (other_version, other_address) =
+                        other.host.split_at(version_end(&other.host));
+                    version.eq_ignore_ascii_case(other_version)
+                        && address == other_address
+                },
+                _ => self.host.eq_ignore_ascii_case(&other.host),
+            }
+    }
+
+    /// Construct an Authority which has the given host name, and no userinfo
+    /// or port number.
+    #[must_use = "you made an Authority; don't you want to use it?"]
+    pub fn from_host<H>(host: H) -> Self
+    where
+        H: Into<Vec<u8>>,
+    {
+        Self::new(None, host, None)
+    }
+
     /// Borrow the host name part of the Authority.
     #[must_use = "why u no use host return value?"]
     pub fn host(&self) -> &[u8] {
         &self.host
     }
 
+    /// Interpret the host name part of the Authority as an IP address, if it
+    /// is one.  This is the inverse of [`set_host_from_ip_addr`].  `None` is
+    /// returned for a registered name or an `IPvFuture` address.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate rhymuri;
+    /// use rhymuri::Authority;
+    /// use std::net::{
+    ///     IpAddr,
+    ///     Ipv6Addr,
+    /// };
+    ///
+    /// # fn main() -> Result<(), rhymuri::Error> {
+    /// let authority = Authority::parse("[::1]:8080")?;
+    /// assert_eq!(
+    ///     Some(IpAddr::V6(Ipv6Addr::LOCALHOST)),
+    ///     authority.host_as_ip_addr()
+    /// );
+    /// assert_eq!(None, Authority::parse("localhost")?.host_as_ip_addr());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`set_host_from_ip_addr`]: #method.set_host_from_ip_addr
+    #[must_use = "you asked for the host as an IP address, so take it"]
+    pub fn host_as_ip_addr(&self) -> Option<std::net::IpAddr> {
+        if self.host_is_ipv_future {
+            return None;
+        }
+        std::str::from_utf8(&self.host).ok()?.parse().ok()
+    }
+
+    /// Check the host name part of the Authority for characters which are
+    /// commonly used to disguise one host name as another.  This is only
+    /// advisory, since such characters are perfectly legal.  The host is
+    /// flagged if it contains a Unicode bidirectional control character
+    /// (such as `U+202E RIGHT-TO-LEFT OVERRIDE`), or a label which mixes
+    /// letters from more than one of the Latin, Greek, Cyrillic and
+    /// Armenian scripts, whose letters are easily confused.  Only hosts
+    /// which are valid UTF-8 are checked; labels in Punycode (`xn--`) are
+    /// not decoded first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate rhymuri;
+    /// use rhymuri::Authority;
+    ///
+    /// # fn main() -> Result<(), rhymuri::Error> {
+    /// // The "а" here is CYRILLIC SMALL LETTER A.
+    /// let authority = Authority::parse("p%D0%B0ypal.com")?;
+    /// assert!(authority.host_has_suspicious_chars());
+    /// assert!(!Authority::parse("paypal.com")?.host_has_suspicious_chars());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use = "you asked whether the host is suspicious, so check"]
+    pub fn host_has_suspicious_chars(&self) -> bool {
+        let script_of = |c: char| match c {
+            'A'..='Z' | 'a'..='z' | '\u{c0}'..='\u{24f}' => Some(0),
+            '\u{370}'..='\u{3ff}' => Some(1),
+            '\u{400}'..='\u{52f}' => Some(2),
+            '\u{531}'..='\u{58f}' => Some(3),
+            _ => None,
+        };
+        std::str::from_utf8(&self.host).is_ok_and(|host| {
+            host.chars().any(|c| {
+                matches!(
+                    c,
+                    '\u{61c}'
+                        | '\u{200e}'
+                        | '\u{200f}'
+                        | '\u{202a}'..='\u{202e}'
+                        | '\u{2066}'..='\u{2069}'
+                )
+            }) || host.split('.').any(|label| {
+                let mut scripts = label.chars().filter_map(script_of);
+                scripts
+                    .next()
+                    .is_some_and(|first| scripts.any(|script| script != first))
+            })
+        })
+    }
+
+    /// Iterate over the labels of the host name part of the Authority, which
+    /// are separated by dots (`.`), or return `None` if the host is an
+    /// IP-literal (an IPv6 or `IPvFuture` address).  A fully-qualified name
+    /// ending in a dot yields a final empty label.  Note that an IPv4
+    /// address is not an IP-literal, so its octets are yielded as labels.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate rhymuri;
+    /// use rhymuri::Authority;
+    ///
+    /// # fn main() -> Result<(), rhymuri::Error> {
+    /// let authority = Authority::parse("www.example.com")?;
+    /// let labels = authority.host_labels().unwrap().collect::<Vec<_>>();
+    /// assert_eq!(vec![&b"www"[..], &b"example"[..], &b"com"[..]], labels);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use = "if you're not going to look at the labels, why split them?"]
+    pub fn host_labels(&self) -> Option<impl Iterator<Item = &[u8]>> {
+        if self.host_is_ipv_future
+            || matches!(
+                std::str::from_utf8(&self.host),
+                Ok(host) if validate_ipv6_address(host).is_ok()
+            )
+        {
+            None
+        } else {
+            Some(self.host.split(|&c| c == b'.'))
+        }
+    }
+
+    /// Convert the host name part of the Authority into a string, replacing
+    /// any non-UTF8 sequences with the Unicode replacement character
+    /// (`U+FFFD`).  Unlike a fallible conversion, this never fails, which is
+    /// handy for log and error messages.
+    #[must_use = "you made a string of the host; don't you want to use it?"]
+    pub fn host_to_string_lossy(&self) -> Cow<'_, str> {
+        String::from_utf8_lossy(&self.host)
+    }
+
+    /// Construct an Authority from its userinfo, host name, and port number
+    /// parts.
+    #[must_use = "you made an Authority; don't you want to use it?"]
+    pub fn new<H>(
+        userinfo: Option<Vec<u8>>,
+        host: H,
+        port: Option<u16>,
+    ) -> Self
+    where
+        H: Into<Vec<u8>>,
+    {
+        Self {
+            userinfo,
+            host: host.into(),
+            host_is_ipv_future: false,
+            port,
+        }
+    }
+
     /// Borrow the port number part of the Authority.
     #[must_use = "why did you get the port number and then throw it away?"]
     pub fn port(&self) -> Option<u16> {
@@ -87,6 +365,71 @@ impl Authority {
         T: Into<Vec<u8>>,
     {
         self.host = host.into();
+        self.host_is_ipv_future = false;
+    }
+
+    /// Change the host name part of the Authority to the textual form of the
+    /// given IP address.  An IPv6 address is stored without brackets, and
+    /// is bracketed when the Authority is formatted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate rhymuri;
+    /// use rhymuri::Authority;
+    /// use std::net::{
+    ///     IpAddr,
+    ///     Ipv6Addr,
+    /// };
+    ///
+    /// let mut authority = Authority::default();
+    /// authority.set_host_from_ip_addr(IpAddr::V6(Ipv6Addr::LOCALHOST));
+    /// assert_eq!("::1".as_bytes(), authority.host());
+    /// assert_eq!("[::1]", authority.to_string());
+    /// ```
+    pub fn set_host_from_ip_addr(
+        &mut self,
+        addr: std::net::IpAddr,
+    ) {
+        self.set_host(addr.to_string());
+    }
+
+    /// Change the host name part of the Authority to the given IPv6 address,
+    /// written without brackets.  The address is bracketed when the Authority
+    /// is formatted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate rhymuri;
+    /// use rhymuri::Authority;
+    ///
+    /// # fn main() -> Result<(), rhymuri::Error> {
+    /// let mut authority = Authority::default();
+    /// authority.set_host_ipv6("::1")?;
+    /// assert_eq!("[::1]", authority.to_string());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the given string isn't a valid IPv6 address, the host is left
+    /// unchanged, and the error found while validating it is returned, such
+    /// as [`Error::TooManyDoubleColons`][TooManyDoubleColons].
+    ///
+    /// [TooManyDoubleColons]: enum.Error.html#variant.TooManyDoubleColons
+    pub fn set_host_ipv6<T>(
+        &mut self,
+        addr: T,
+    ) -> Result<(), Error>
+    where
+        T: AsRef<str>,
+    {
+        let addr = addr.as_ref();
+        validate_ipv6_address(addr)?;
+        self.set_host(addr);
+        Ok(())
     }
 
     /// Change the port number part of the Authority.
@@ -97,12 +440,73 @@ impl Authority {
         self.port = port;
     }
 
+    /// Produce the whole Authority as a single byte string, in the same form
+    /// as [`Display`] does, except that the userinfo and host are not
+    /// percent-encoded.  For example, the Authority parsed from
+    /// `b%40b@www.example.com:8080` produces `b@b@www.example.com:8080`.
+    /// The result can't always be parsed back into an Authority, but is
+    /// useful for hashing or logging.  An IPv6 address host is still
+    /// enclosed in brackets, so that it can't be confused with the port
+    /// number.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate rhymuri;
+    /// use rhymuri::Authority;
+    ///
+    /// # fn main() -> Result<(), rhymuri::Error> {
+    /// let authority = Authority::parse("bob%20smith@www.example.com:8080")?;
+    /// assert_eq!(
+    ///     b"bob smith@www.example.com:8080".to_vec(),
+    ///     authority.to_bytes()
+    /// );
+    /// assert_eq!("bob%20smith@www.example.com:8080", authority.to_string());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Display`]: https://doc.rust-lang.org/std/fmt/trait.Display.html
+    #[must_use = "why make the bytes if you're not going to use them?"]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        if let Some(userinfo) = &self.userinfo {
+            bytes.extend_from_slice(userinfo);
+            bytes.push(b'@');
+        }
+        let host_is_ip_literal = self.host_is_ipv_future
+            || matches!(
+                std::str::from_utf8(&self.host),
+                Ok(host) if validate_ipv6_address(host).is_ok()
+            );
+        if host_is_ip_literal {
+            bytes.push(b'[');
+            bytes.extend_from_slice(&self.host);
+            bytes.push(b']');
+        } else {
+            bytes.extend_from_slice(&self.host);
+        }
+        if let Some(port) = self.port {
+            bytes.extend_from_slice(format!(":{}", port).as_bytes());
+        }
+        bytes
+    }
+
     /// Borrow the userinfo part of the Authority.
     #[must_use = "security breach... security breach... userinfo not used"]
     pub fn userinfo(&self) -> Option<&[u8]> {
         self.userinfo.as_deref()
     }
 
+    /// Convert the userinfo part (if any) of the Authority into a string,
+    /// replacing any non-UTF8 sequences with the Unicode replacement
+    /// character (`U+FFFD`).  Unlike a fallible conversion, this never fails,
+    /// which is handy for log and error messages.
+    #[must_use = "security breach... security breach... userinfo not used"]
+    pub fn userinfo_to_string_lossy(&self) -> Option<Cow<'_, str>> {
+        self.userinfo.as_deref().map(String::from_utf8_lossy)
+    }
+
     /// Interpret the given string as the Authority component of a URI,
     /// separating its various subcomponents, returning an `Authority` value
     /// containing them.
@@ -112,30 +516,152 @@ impl Authority {
     /// There are many ways to screw up the Authority part of URI string, and
     /// this function will let you know what's up by returning a variant of the
     /// [`Error`](enum.Error.html) type.
+    ///
+    /// For example, an Authority may contain only one unencoded at sign
+    /// (`@`), which delimits the userinfo from the host.  Any more, as in
+    /// `a@b@www.example.com`, are taken to be part of the host, where they
+    /// aren't allowed, and so
+    /// [`Error::IllegalCharacter`](enum.Error.html#variant.IllegalCharacter)
+    /// is returned with [`Context::Host`](enum.Context.html#variant.Host).
+    /// An at sign within the userinfo must be percent-encoded (`%40`).
     #[must_use = "you parsed it; don't you want the results?"]
     pub fn parse<T>(authority_string: T) -> Result<Self, Error>
     where
         T: AsRef<str>,
     {
+        Self::parse_with_options(authority_string, ParseOptions::default())
+    }
+
+    /// This is the same as [`parse`], except that the given options control
+    /// how the string is interpreted.
+    ///
+    /// # Errors
+    ///
+    /// In addition to the errors returned by [`parse`], other variants of the
+    /// [`Error`](enum.Error.html) type may be returned, depending on the
+    /// options given.  See [`ParseOptions`] for details.
+    ///
+    /// [`parse`]: #method.parse
+    /// [`ParseOptions`]: struct.ParseOptions.html
+    #[must_use = "you parsed it; don't you want the results?"]
+    pub fn parse_with_options<T>(
+        authority_string: T,
+        options: ParseOptions,
+    ) -> Result<Self, Error>
+    where
+        T: AsRef<str>,
+    {
+        let authority_string = authority_string.as_ref();
+        let mut budget = DecodeBudget::new(options.limits.max_decoded_len);
+        Self::parse_within_budget(authority_string, 0, options, &mut budget)
+    }
+
+    // Parse the authority string, which starts at the given byte offset in
+    // the string being parsed, spending the given budget on each byte
+    // decoded from the userinfo and host.
+    pub(crate) fn parse_within_budget(
+        authority_string: &str,
+        offset: usize,
+        options: ParseOptions,
+        budget: &mut DecodeBudget,
+    ) -> Result<Self, Error> {
         let (userinfo, host_port_string) =
-            Self::parse_userinfo(authority_string.as_ref())?;
-        let (host, port) = parse_host_port(host_port_string)?;
+            Self::parse_userinfo(authority_string, offset, budget)?;
+        if options.reject_userinfo && userinfo.is_some() {
+            return Err(budget.fail_at(
+                offset,
+                Context::Userinfo,
+                Error::UserinfoNotAllowed,
+            ));
+        }
+        let host_port_start =
+            offset + authority_string.len() - host_port_string.len();
+        let (host, port) =
+            parse_host_port(host_port_string, host_port_start, budget)?;
+        let host_is_ipv_future = matches!(
+            host_port_string.get(0..2),
+            Some(prefix) if prefix.eq_ignore_ascii_case("[v")
+        );
+        // The only dots in an IPv6 address are those of an embedded IPv4
+        // address.
+        if options.reject_embedded_ipv4
+            && !host_is_ipv_future
+            && host.contains(&b'.')
+            && matches!(
+                std::str::from_utf8(&host),
+                Ok(host) if validate_ipv6_address(host).is_ok()
+            )
+        {
+            return Err(budget.fail_at(
+                host_port_start,
+                Context::Host,
+                Error::EmbeddedIpv4NotAllowed,
+            ));
+        }
+        if options.strict {
+            if host.is_empty() && port.is_some() {
+                return Err(budget.fail_at(
+                    host_port_start,
+                    Context::Host,
+                    Error::EmptyAuthorityWithPort,
+                ));
+            }
+            // Check any IPv4 address, whether it's the whole host or
+            // embedded at the end of an IPv6 address.  An `IPvFuture`
+            // address is left alone, since its meaning depends on its
+            // version.
+            let host_string = String::from_utf8_lossy(&host);
+            let ipv4_address = if host_is_ipv_future {
+                None
+            } else if host_port_string.starts_with('[') {
+                host_string.rsplit(':').next().filter(|last| last.contains('.'))
+            } else {
+                Some(&*host_string)
+            };
+            if let Some(ipv4_address) = ipv4_address {
+                reject_non_decimal_octets(ipv4_address).map_err(|error| {
+                    budget.fail_at(host_port_start, Context::Host, error)
+                })?;
+            }
+        }
         Ok(Self {
             userinfo,
             host,
+            host_is_ipv_future,
             port,
         })
     }
 
-    fn parse_userinfo(
-        authority: &str
-    ) -> Result<(Option<Vec<u8>>, &str), Error> {
+    // If the host is an `IPvFuture` address, normalize the case of its "v"
+    // prefix and version number.  The rest of the address is left alone,
+    // since its meaning depends on the version.
+    pub(crate) fn normalize_ipv_future(&mut self) {
+        if self.host_is_ipv_future {
+            let version_end = self
+                .host
+                .iter()
+                .position(|&c| c == b'.')
+                .unwrap_or(self.host.len());
+            self.host[0] = b'v';
+            self.host[1..version_end].make_ascii_uppercase();
+        }
+    }
+
+    fn parse_userinfo<'a>(
+        authority: &'a str,
+        offset: usize,
+        budget: &mut DecodeBudget,
+    ) -> Result<(Option<Vec<u8>>, &'a str), Error> {
+        // Only the first '@' delimits the userinfo.  Any others are left in
+        // the host, where they'll be rejected as illegal characters.
         Ok(match authority.find('@') {
             Some(delimiter) => (
                 Some(decode_element(
                     &authority[0..delimiter],
+                    offset,
                     &USER_INFO_NOT_PCT_ENCODED,
                     Context::Userinfo,
+                    budget,
                 )?),
                 &authority[delimiter + 1..],
             ),
@@ -158,6 +684,12 @@ impl std::fmt::Display for Authority {
         }
         let host_to_string = String::from_utf8(self.host.clone());
         match host_to_string {
+            // An `IPvFuture` address can only be written in brackets.  It's
+            // told apart by how it was parsed rather than by its form, since
+            // a registered name such as `v1.example` could have that form.
+            Ok(host_to_string) if self.host_is_ipv_future => {
+                write!(f, "[{}]", host_to_string)?;
+            },
             Ok(host_to_string)
                 if validate_ipv6_address(&host_to_string).is_ok() =>
             {
@@ -282,6 +814,7 @@ mod tests {
             ("1.2.3.4", "1.2.3.4").into(),
             ("[v7.:]", "v7.:").into(),
             ("[v7.aB]", "v7.aB").into(),
+            ("[V7.aB]", "V7.aB").into(),
         ];
         for test_vector in test_vectors {
             let authority = Authority::parse(test_vector.authority_string());
@@ -316,4 +849,366 @@ mod tests {
             assert_eq!(normalized_host.as_bytes(), authority.host());
         }
     }
+
+    #[test]
+    fn construct_from_parts() {
+        let mut expected = Authority::default();
+        expected.set_userinfo(Some(b"bob".to_vec()));
+        expected.set_host("www.example.com");
+        expected.set_port(Some(8080));
+        assert_eq!(
+            expected,
+            Authority::new(
+                Some(b"bob".to_vec()),
+                "www.example.com",
+                Some(8080)
+            )
+        );
+        let mut expected = Authority::default();
+        expected.set_host("www.example.com");
+        assert_eq!(expected, Authority::from_host("www.example.com"));
+        assert_eq!(expected, Authority::new(None, "www.example.com", None));
+    }
+
+    #[test]
+    fn empty_host_with_port() {
+        let authority = Authority::parse(":8080");
+        assert!(authority.is_ok());
+        let authority = authority.unwrap();
+        assert_eq!(b"", authority.host());
+        assert_eq!(Some(8080), authority.port());
+        let options = ParseOptions {
+            strict: true,
+            ..ParseOptions::default()
+        };
+        assert_eq!(
+            Err(Error::EmptyAuthorityWithPort),
+            Authority::parse_with_options(":8080", options)
+        );
+        assert!(Authority::parse_with_options(":", options).is_ok());
+        assert!(Authority::parse_with_options("a:8080", options).is_ok());
+    }
+
+    #[test]
+    fn set_host_from_ip_addr() {
+        use std::net::{
+            IpAddr,
+            Ipv4Addr,
+            Ipv6Addr,
+        };
+        let mut authority = Authority::default();
+        authority.set_host_from_ip_addr(IpAddr::V4(Ipv4Addr::new(1, 2, 3, 4)));
+        authority.set_port(Some(8080));
+        assert_eq!("1.2.3.4:8080", authority.to_string());
+        authority.set_host_from_ip_addr(IpAddr::V6(Ipv6Addr::new(
+            0x2001, 0xdb8, 0, 0, 0, 0, 0, 1,
+        )));
+        assert_eq!(b"2001:db8::1", authority.host());
+        assert_eq!("[2001:db8::1]:8080", authority.to_string());
+        authority.set_host_from_ip_addr(IpAddr::V6(
+            Ipv4Addr::new(1, 2, 3, 4).to_ipv6_mapped(),
+        ));
+        assert_eq!("[::ffff:1.2.3.4]:8080", authority.to_string());
+        assert_eq!(
+            Ok(authority.clone()),
+            Authority::parse(authority.to_string())
+        );
+    }
+
+    #[test]
+    fn set_host_ipv6() {
+        let mut authority = Authority::default();
+        assert_eq!(Ok(()), authority.set_host_ipv6("::1"));
+        assert_eq!(b"::1", authority.host());
+        assert_eq!("[::1]", authority.to_string());
+        authority.set_port(Some(8080));
+        assert_eq!(Ok(()), authority.set_host_ipv6("2001:db8::ffff:1.2.3.4"));
+        assert_eq!("[2001:db8::ffff:1.2.3.4]:8080", authority.to_string());
+        assert_eq!(
+            Ok(authority.clone()),
+            Authority::parse(authority.to_string())
+        );
+        assert_eq!(
+            Err(Error::TooManyDoubleColons),
+            authority.set_host_ipv6("1::2::3")
+        );
+        assert!(authority.set_host_ipv6("[::1]").is_err());
+        assert!(authority.set_host_ipv6("www.example.com").is_err());
+        assert_eq!(b"2001:db8::ffff:1.2.3.4", authority.host());
+    }
+
+    #[test]
+    fn to_bytes() {
+        let authority =
+            Authority::parse("a%40b%3Ac@www.example.com:8080").unwrap();
+        assert_eq!(
+            b"a@b:c@www.example.com:8080".to_vec(),
+            authority.to_bytes()
+        );
+        assert_eq!("a%40b:c@www.example.com:8080", authority.to_string());
+        let authority = Authority::parse("%FF@x%2Fy").unwrap();
+        assert_eq!(b"\xFF@x/y".to_vec(), authority.to_bytes());
+        let authority = Authority::parse("[::1]:80").unwrap();
+        assert_eq!(b"[::1]:80".to_vec(), authority.to_bytes());
+        let authority = Authority::parse("").unwrap();
+        assert_eq!(Vec::<u8>::new(), authority.to_bytes());
+    }
+
+    #[test]
+    fn multiple_at_signs() {
+        let test_vectors = ["a@b@host", "a@b@[::1]", "a@@host", "a@host@"];
+        for test_vector in &test_vectors {
+            assert_eq!(
+                Err(Error::IllegalCharacter(Context::Host)),
+                Authority::parse(test_vector),
+                "{}",
+                test_vector
+            );
+        }
+        let authority = Authority::parse("a%40b@host").unwrap();
+        assert_eq!(Some(&b"a@b"[..]), authority.userinfo());
+        assert_eq!(b"host", authority.host());
+        assert_eq!(
+            "illegal character in host",
+            Error::IllegalCharacter(Context::Host).to_string()
+        );
+    }
+
+    #[test]
+    fn lossy_strings() {
+        let authority =
+            Authority::parse("bob%FFsmith@www.%E1example.com").unwrap();
+        assert_eq!(
+            Some("bob\u{FFFD}smith"),
+            authority.userinfo_to_string_lossy().as_deref()
+        );
+        assert_eq!("www.\u{FFFD}example.com", authority.host_to_string_lossy());
+        let authority = Authority::parse("bob@www.example.com").unwrap();
+        assert_eq!(
+            Some("bob"),
+            authority.userinfo_to_string_lossy().as_deref()
+        );
+        assert!(matches!(authority.host_to_string_lossy(), Cow::Borrowed(_)));
+        let authority = Authority::parse("www.example.com").unwrap();
+        assert_eq!(None, authority.userinfo_to_string_lossy());
+    }
+
+    #[test]
+    fn non_decimal_octets() {
+        let options = ParseOptions {
+            strict: true,
+            ..ParseOptions::default()
+        };
+        let test_vectors = [
+            "1.2.3.04",
+            "1.2.3.04:80",
+            "010.0.0.1",
+            "[::ffff:1.2.3.04]",
+            "1.2.3.%30%34",
+            "0x7f.0.0.1",
+        ];
+        for test_vector in &test_vectors {
+            assert!(Authority::parse(test_vector).is_ok(), "{}", test_vector);
+            assert_eq!(
+                Err(Error::InvalidDecimalOctet),
+                Authority::parse_with_options(test_vector, options),
+                "{}",
+                test_vector
+            );
+        }
+        let test_vectors = [
+            "1.2.3.4",
+            "1.2.3.40:80",
+            "[::ffff:1.2.3.4]",
+            "[v7.01]",
+            "[v7.1:01.2]",
+            "[::01]",
+            "a.01",
+        ];
+        for test_vector in &test_vectors {
+            assert!(
+                Authority::parse_with_options(test_vector, options).is_ok(),
+                "{}",
+                test_vector
+            );
+        }
+    }
+
+    #[test]
+    fn components() {
+        let test_vectors = ["nobody@www.example.com:8080", "[::1]", ""];
+        for test_vector in &test_vectors {
+            let authority = Authority::parse(test_vector).unwrap();
+            let (userinfo, host, port) = authority.components();
+            assert_eq!(authority.userinfo(), userinfo);
+            assert_eq!(authority.host(), host);
+            assert!(std::ptr::eq(authority.host(), host));
+            assert_eq!(authority.port(), port);
+        }
+    }
+
+    #[test]
+    fn embedded_ipv4() {
+        let options = ParseOptions {
+            reject_embedded_ipv4: true,
+            ..ParseOptions::default()
+        };
+        let test_vectors = ["[::ffff:1.2.3.4]", "[::ffff:1.2.3.4]:80"];
+        for test_vector in &test_vectors {
+            let authority = Authority::parse(test_vector);
+            assert!(authority.is_ok(), "{}", test_vector);
+            assert_eq!(&b"::ffff:1.2.3.4"[..], authority.unwrap().host());
+            assert_eq!(
+                Err(Error::EmbeddedIpv4NotAllowed),
+                Authority::parse_with_options(test_vector, options),
+                "{}",
+                test_vector
+            );
+        }
+        let test_vectors =
+            ["[::ffff:102:304]", "1.2.3.4", "[v7.1.2:3]", "a%3A.b", "a.b:80"];
+        for test_vector in &test_vectors {
+            assert!(
+                Authority::parse_with_options(test_vector, options).is_ok(),
+                "{}",
+                test_vector
+            );
+        }
+    }
+
+    #[test]
+    fn display_width() {
+        let test_vectors = [
+            "www.example.com",
+            "bob@www.example.com:8080",
+            "b%40b:%20@%C3%A9x.com:0",
+            "[::ffff:1.2.3.4]:65535",
+            "[v7.aB:c]:8",
+            "1.2.3.4:9",
+            ":10",
+            "",
+        ];
+        for test_vector in &test_vectors {
+            let authority = Authority::parse(test_vector).unwrap();
+            assert_eq!(
+                authority.to_string().chars().count(),
+                authority.display_width(),
+                "{}",
+                test_vector
+            );
+        }
+        let mut authority = Authority::default();
+        authority.set_host("h\u{e9}".as_bytes());
+        authority.set_userinfo(Some(vec![0xFF, b':']));
+        assert_eq!("%FF:@h%C3%A9".len(), authority.display_width());
+        assert_eq!(authority.to_string().len(), authority.display_width());
+    }
+
+    #[test]
+    fn host_labels() {
+        let authority = Authority::parse("www.example.com.").unwrap();
+        assert_eq!(
+            Some(vec![&b"www"[..], &b"example"[..], &b"com"[..], &b""[..]]),
+            authority.host_labels().map(Iterator::collect)
+        );
+        let authority = Authority::parse("[::1]").unwrap();
+        assert!(authority.host_labels().is_none());
+        let authority = Authority::parse("[v7.aB]").unwrap();
+        assert!(authority.host_labels().is_none());
+    }
+
+    #[test]
+    // NOTE: This lint is disabled because it's triggered inside the
+    // `named_tuple!` macro expansion.
+    #[allow(clippy::from_over_into)]
+    fn host_has_suspicious_chars() {
+        named_tuple!(
+            struct TestVector {
+                authority_string: &'static str,
+                suspicious: bool,
+            }
+        );
+        let test_vectors: &[TestVector] = &[
+            ("www.example.com", false).into(),
+            ("[::1]", false).into(),
+            ("www.ex%E2%80%AEelpmaxe.com", true).into(),
+            ("%E2%80%8Fexample.com", true).into(),
+            ("p%D0%B0ypal.com", true).into(),
+            ("%D0%BF%D1%80%D0%B8%D0%BC%D0%B5%D1%80.com", false).into(),
+            ("caf%C3%A9.com", false).into(),
+            ("%CE%B1b.com", true).into(),
+            ("%E4%BE%8B%E3%81%88.jp", false).into(),
+            ("%FF.com", false).into(),
+        ];
+        for test_vector in test_vectors {
+            let authority =
+                Authority::parse(test_vector.authority_string()).unwrap();
+            assert_eq!(
+                *test_vector.suspicious(),
+                authority.host_has_suspicious_chars(),
+                "{}",
+                test_vector.authority_string()
+            );
+        }
+    }
+
+    #[test]
+    // NOTE: This lint is disabled because it's triggered inside the
+    // `named_tuple!` macro expansion.
+    #[allow(clippy::from_over_into)]
+    fn eq_ignore_case() {
+        named_tuple!(
+            struct TestVector {
+                authority_string: &'static str,
+                other_string: &'static str,
+                equal: bool,
+            }
+        );
+        let test_vectors: &[TestVector] = &[
+            ("user@Example.com:80", "user@example.com:80", true).into(),
+            ("user@example.com:80", "User@example.com:80", false).into(),
+            ("user@example.com:80", "example.com:80", false).into(),
+            ("example.com:80", "example.com:81", false).into(),
+            ("example.com:80", "example.com", false).into(),
+            ("example.com", "example.org", false).into(),
+            ("[::1]", "[0:0::1]", true).into(),
+            ("[2001:DB8::A]", "[2001:db8:0:0:0:0:0:a]", true).into(),
+            ("[::ffff:1.2.3.4]", "[::ffff:102:304]", true).into(),
+            ("[::1]", "[::2]", false).into(),
+            ("1.2.3.4", "1.2.3.4", true).into(),
+            ("[v7.aB]", "[V7.aB]", true).into(),
+            ("[v7.aB]", "[v7.Ab]", false).into(),
+            ("[v7.ab]", "v7.ab", false).into(),
+            ("v7.ab", "[v7.ab]", false).into(),
+        ];
+        for test_vector in test_vectors {
+            let authority =
+                Authority::parse(test_vector.authority_string()).unwrap();
+            let other = Authority::parse(test_vector.other_string()).unwrap();
+            assert_eq!(
+                *test_vector.equal(),
+                authority.eq_ignore_case(&other),
+                "{} {}",
+                test_vector.authority_string(),
+                test_vector.other_string()
+            );
+        }
+    }
+
+    #[test]
+    fn ipv_future_brackets() {
+        let test_vectors = ["[v7.aB]", "u@[V7.aB:c]:80", "[vF.~!$&'()*+,;=:]"];
+        for test_vector in &test_vectors {
+            let authority = Authority::parse(test_vector).unwrap();
+            assert_eq!(*test_vector, authority.to_string());
+            assert_eq!(test_vector.as_bytes(), authority.to_bytes());
+            assert_eq!(
+                authority,
+                Authority::parse(authority.to_string()).unwrap()
+            );
+        }
+        let mut authority = Authority::default();
+        authority.set_host("v1.example");
+        assert_eq!("v1.example", authority.to_string());
+    }
 }
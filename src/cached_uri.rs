@@ -0,0 +1,128 @@
+#![warn(clippy::pedantic)]
+
+use once_cell::unsync::OnceCell;
+
+use super::uri::Uri;
+
+/// This wraps a [`Uri`] which won't be changed, along with its string form,
+/// which is generated the first time it's needed and then kept, so that
+/// code which formats the same URI many times only pays for it once.  Only
+/// read access to the wrapped URI is given, so the kept string can never go
+/// stale.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate rhymuri;
+/// use rhymuri::{
+///     CachedUri,
+///     Uri,
+/// };
+///
+/// # fn main() -> Result<(), rhymuri::Error> {
+/// let uri = CachedUri::new(Uri::parse("http://www.example.com/foo")?);
+/// assert_eq!("http://www.example.com/foo", uri.as_str());
+/// assert_eq!(Some(&b"www.example.com"[..]), uri.as_uri().host());
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Uri`]: struct.Uri.html
+#[derive(Clone, Debug, Default)]
+pub struct CachedUri {
+    uri: Uri,
+    string: OnceCell<String>,
+}
+
+impl CachedUri {
+    /// Borrow the string form of the URI, generating it if this is the first
+    /// time it's needed.
+    #[must_use = "why ask for the string if you're not going to use it?"]
+    pub fn as_str(&self) -> &str {
+        self.string.get_or_init(|| self.uri.to_string())
+    }
+
+    /// Borrow the wrapped [`Uri`](struct.Uri.html).
+    #[must_use = "why borrow the URI if you're not going to look at it?"]
+    pub fn as_uri(&self) -> &Uri {
+        &self.uri
+    }
+
+    /// Consume the wrapper and return the [`Uri`](struct.Uri.html) it wraps.
+    #[must_use = "you unwrapped the URI; don't drop it on the floor"]
+    pub fn into_uri(self) -> Uri {
+        self.uri
+    }
+
+    /// Wrap the given [`Uri`](struct.Uri.html).  Its string form isn't
+    /// generated until it's needed.
+    #[must_use = "you wrapped the URI; don't you want to use it?"]
+    pub fn new(uri: Uri) -> Self {
+        Self {
+            uri,
+            string: OnceCell::new(),
+        }
+    }
+}
+
+impl std::fmt::Display for CachedUri {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<Uri> for CachedUri {
+    fn from(uri: Uri) -> Self {
+        Self::new(uri)
+    }
+}
+
+impl PartialEq for CachedUri {
+    fn eq(
+        &self,
+        other: &Self,
+    ) -> bool {
+        // Whether or not the string form has been generated yet doesn't
+        // matter.
+        self.uri == other.uri
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn as_str() {
+        let test_vectors = [
+            "http://bob@www.example.com:8080/abc/def?foobar#ch2",
+            "mailto:bob@example.com",
+            "/%20",
+            "",
+        ];
+        for test_vector in &test_vectors {
+            let uri = Uri::parse(test_vector).unwrap();
+            let cached_uri = CachedUri::from(uri.clone());
+            let first = cached_uri.as_str();
+            let second = cached_uri.as_str();
+            assert_eq!(uri.to_string(), first);
+            assert!(std::ptr::eq(first, second));
+            assert_eq!(uri.to_string(), cached_uri.to_string());
+            assert_eq!(&uri, cached_uri.as_uri());
+            assert_eq!(uri, cached_uri.into_uri());
+        }
+    }
+
+    #[test]
+    fn eq_ignores_cache() {
+        let uri = Uri::parse("http://www.example.com/").unwrap();
+        let cached_uri1 = CachedUri::new(uri.clone());
+        let cached_uri2 = CachedUri::new(uri);
+        let _ = cached_uri1.as_str();
+        assert_eq!(cached_uri1, cached_uri2);
+    }
+}
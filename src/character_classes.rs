@@ -59,6 +59,16 @@ pub static PCHAR_NOT_PCT_ENCODED: Lazy<HashSet<char>> = Lazy::new(|| {
         .collect()
 });
 
+// This is the character set corresponds to the "segment-nz-nc" syntax
+// specified in RFC 3986 (https://tools.ietf.org/html/rfc3986),
+// leaving out "pct-encoded".  It is the same as `PCHAR_NOT_PCT_ENCODED`,
+// except that ':' is excluded, so that the first segment of the path of a
+// relative reference isn't mistaken for a scheme.
+pub static SEGMENT_NZ_NC_NOT_PCT_ENCODED: Lazy<HashSet<char>> =
+    Lazy::new(|| {
+        PCHAR_NOT_PCT_ENCODED.iter().copied().filter(|&c| c != ':').collect()
+    });
+
 // This is the character set corresponds to the "query" syntax
 // and the "fragment" syntax
 // specified in RFC 3986 (https://tools.ietf.org/html/rfc3986),
@@ -88,6 +98,12 @@ pub static QUERY_NOT_PCT_ENCODED_WITHOUT_PLUS: Lazy<HashSet<char>> =
             .collect()
     });
 
+// This is the character set containing the characters which delimit query
+// parameter pairs and their keys and values.  Where these are found
+// percent-encoded in a query, they are part of a key or value instead.
+pub static QUERY_PAIR_DELIMITERS: Lazy<HashSet<char>> =
+    Lazy::new(|| ['&', ';', '='].iter().copied().collect());
+
 // This is the character set corresponds to the "userinfo" syntax
 // specified in RFC 3986 (https://tools.ietf.org/html/rfc3986),
 // leaving out "pct-encoded".
@@ -4,41 +4,197 @@ use std::{
     fmt::Write,
 };
 
+use once_cell::sync::Lazy;
+
 use super::{
     context::Context,
     error::Error,
     percent_encoded_character_decoder::PercentEncodedCharacterDecoder,
 };
 
+// This is the empty character set, for decoding an element without noting
+// any of its percent-encoded characters.
+static NOTHING_NOTED: Lazy<HashSet<char>> = Lazy::new(HashSet::new);
+
+// Decode the given base64 encoding (RFC 4648 section 4), whose padding is
+// optional.  `None` is returned if the encoding contains any character
+// outside the base64 alphabet, ends in a partial character, has padding
+// which doesn't bring it to a multiple of four characters, or has leftover
+// bits at the end which aren't zero.
+pub fn decode_base64(encoding: &[u8]) -> Option<Vec<u8>> {
+    let (encoding, padding) = encoding.split_at(
+        encoding.iter().rposition(|&c| c != b'=').map_or(0, |i| i + 1),
+    );
+    if !padding.is_empty()
+        && (encoding.len() % 4 == 0
+            || (encoding.len() + padding.len()) % 4 != 0)
+    {
+        return None;
+    }
+    let mut decoded = Vec::with_capacity(encoding.len() * 3 / 4);
+    let mut buffer = 0_u32;
+    let mut bits = 0;
+    for &c in encoding {
+        let value = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return None,
+        };
+        buffer = (buffer << 6) | u32::from(value);
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            decoded.push((buffer >> bits).to_le_bytes()[0]);
+        }
+    }
+    if bits < 6 && buffer & ((1 << bits) - 1) == 0 {
+        Some(decoded)
+    } else {
+        None
+    }
+}
+
+// This keeps track of how many more bytes may be decoded from the string
+// being parsed, as limited by the `max_decoded_len` parse limit, so that
+// decoding stops as soon as the limit is passed.  Where in the string that
+// happens, or where parsing otherwise fails, is noted, for reporting the
+// error: the byte offset, and the part of the URI found there.
+#[derive(Clone, Copy, Debug)]
+pub struct DecodeBudget {
+    remaining: Option<usize>,
+    error_at: Option<(usize, Context)>,
+}
+
+impl DecodeBudget {
+    pub fn new(max_decoded_len: Option<usize>) -> Self {
+        Self {
+            remaining: max_decoded_len,
+            error_at: None,
+        }
+    }
+
+    pub fn unlimited() -> Self {
+        Self::new(None)
+    }
+
+    pub fn error_at(&self) -> Option<(usize, Context)> {
+        self.error_at
+    }
+
+    // Note that parsing failed at the given byte offset in the string, in
+    // the given part of the URI, and hand back the error found there, to be
+    // returned.
+    pub fn fail_at(
+        &mut self,
+        offset: usize,
+        context: Context,
+        error: Error,
+    ) -> Error {
+        self.error_at = Some((offset, context));
+        error
+    }
+
+    // Spend one byte decoded from the character at the given byte offset in
+    // the string, in the given part of the URI.
+    pub fn spend(
+        &mut self,
+        offset: usize,
+        context: Context,
+    ) -> Result<(), Error> {
+        match &mut self.remaining {
+            Some(0) => {
+                Err(self.fail_at(offset, context, Error::DecodedTooLarge))
+            },
+            Some(remaining) => {
+                *remaining -= 1;
+                Ok(())
+            },
+            None => Ok(()),
+        }
+    }
+}
+
+// Decode the given element, which starts at the given byte offset in the
+// string being parsed, spending the given budget on each byte decoded.
 pub fn decode_element<T>(
     element: T,
+    offset: usize,
     allowed_characters: &'static HashSet<char>,
     context: Context,
+    budget: &mut DecodeBudget,
 ) -> Result<Vec<u8>, Error>
 where
     T: AsRef<str>,
 {
-    let mut decoding_pec = false;
+    decode_element_noting_encoded(
+        element,
+        offset,
+        allowed_characters,
+        &NOTHING_NOTED,
+        context,
+        budget,
+    )
+    .map(|(decoded, _)| decoded)
+}
+
+// This is the same as `decode_element`, except that the positions (in the
+// decoded element) of any percent-encoded characters found in `noted` are
+// also returned, in ascending order, so that they can be told apart from the
+// same characters appearing unencoded.
+pub fn decode_element_noting_encoded<T>(
+    element: T,
+    offset: usize,
+    allowed_characters: &'static HashSet<char>,
+    noted: &'static HashSet<char>,
+    context: Context,
+    budget: &mut DecodeBudget,
+) -> Result<(Vec<u8>, Vec<usize>), Error>
+where
+    T: AsRef<str>,
+{
+    let element = element.as_ref();
+    let mut pec_start = None;
     let mut pec_decoder = PercentEncodedCharacterDecoder::new();
-    element
-        .as_ref()
-        .chars()
-        .filter_map(|c| {
-            if decoding_pec {
-                pec_decoder.next(c).map_err(Into::into).transpose().map(|c| {
-                    decoding_pec = false;
-                    c
-                })
-            } else if c == '%' {
-                decoding_pec = true;
-                None
-            } else if allowed_characters.contains(&c) {
-                Some(Ok(c as u8))
-            } else {
-                Some(Err(Error::IllegalCharacter(context)))
+    let mut decoded = Vec::new();
+    let mut positions = Vec::new();
+    for (i, c) in element.char_indices() {
+        if let Some(start) = pec_start {
+            if let Some(ci) = pec_decoder.next(c).map_err(|error| {
+                budget.fail_at(offset + start, context, error)
+            })? {
+                pec_start = None;
+                budget.spend(offset + start, context)?;
+                if noted.contains(&char::from(ci)) {
+                    positions.push(decoded.len());
+                }
+                decoded.push(ci);
             }
-        })
-        .collect()
+        } else if c == '%' {
+            pec_start = Some(i);
+        } else if allowed_characters.contains(&c) {
+            budget.spend(offset + i, context)?;
+            decoded.push(c as u8);
+        } else {
+            return Err(budget.fail_at(
+                offset + i,
+                context,
+                Error::IllegalCharacter(context),
+            ));
+        }
+    }
+    // A percent sign must be followed by two hexadecimal digits, even at
+    // the end of the element.
+    if let Some(start) = pec_start {
+        return Err(budget.fail_at(
+            offset + start,
+            context,
+            Error::IllegalPercentEncoding,
+        ));
+    }
+    Ok((decoded, positions))
 }
 
 pub fn encode_element(
@@ -54,3 +210,42 @@ pub fn encode_element(
     }
     encoding
 }
+
+// This is the same as `encode_element`, except that the characters at the
+// given positions (in ascending order) are always percent-encoded.
+pub fn encode_element_escaping(
+    element: &[u8],
+    allowed_characters: &HashSet<char>,
+    escaped: &[usize],
+) -> String {
+    let mut encoding = String::with_capacity(element.len());
+    let mut escaped = escaped.iter().peekable();
+    for (i, ci) in element.iter().enumerate() {
+        let c = char::from(*ci);
+        if escaped.next_if_eq(&&i).is_none() && allowed_characters.contains(&c)
+        {
+            encoding.push(c);
+        } else {
+            write!(encoding, "%{:02X}", ci).unwrap();
+        }
+    }
+    encoding
+}
+
+// Determine the length of the string `encode_element` would produce for the
+// given element, without actually producing it.
+pub fn encoded_element_len(
+    element: &[u8],
+    allowed_characters: &HashSet<char>,
+) -> usize {
+    element
+        .iter()
+        .map(|&ci| {
+            if allowed_characters.contains(&char::from(ci)) {
+                1
+            } else {
+                3
+            }
+        })
+        .sum()
+}
@@ -0,0 +1,96 @@
+#![warn(clippy::pedantic)]
+
+use std::ops::Range;
+
+/// This holds the byte ranges of the components of a URI string, as
+/// returned by [`Uri::component_spans`], so that the components can be
+/// sliced out of the original string without decoding or copying them.
+/// The delimiters between components (such as `://`, `@`, `?` and `#`) are
+/// not included in any range.
+///
+/// [`Uri::component_spans`]: struct.Uri.html#method.component_spans
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ComponentSpans {
+    /// The range of the scheme, if any.
+    pub scheme: Option<Range<usize>>,
+
+    /// The range of the whole authority, including any userinfo and port,
+    /// if the URI has an authority.
+    pub authority: Option<Range<usize>>,
+
+    /// The range of the userinfo, if the authority has any.
+    pub userinfo: Option<Range<usize>>,
+
+    /// The range of the host, including the brackets around an IP-literal,
+    /// if the URI has an authority.
+    pub host: Option<Range<usize>>,
+
+    /// The range of the port number, if the authority has a colon after the
+    /// host.  The range is empty if no digits follow the colon.
+    pub port: Option<Range<usize>>,
+
+    /// The range of the path, which is empty if the path is empty.
+    pub path: Range<usize>,
+
+    /// The range of the query, if any.
+    pub query: Option<Range<usize>>,
+
+    /// The range of the fragment, if any.
+    pub fragment: Option<Range<usize>>,
+}
+
+impl ComponentSpans {
+    // Split up the given URI string, which is assumed to have already been
+    // parsed successfully, the same way `Uri::parse` does it.
+    pub(crate) fn locate(input: &str) -> Self {
+        let scheme_search_end = input.find('/').unwrap_or(input.len());
+        let (scheme, rest_start) = match input[..scheme_search_end].find(':') {
+            Some(scheme_end) => (Some(0..scheme_end), scheme_end + 1),
+            None => (None, 0),
+        };
+        let path_end = input[rest_start..]
+            .find(&['?', '#'][..])
+            .map_or(input.len(), |i| rest_start + i);
+        let mut spans = Self {
+            scheme,
+            path: rest_start..path_end,
+            ..Self::default()
+        };
+        if input[rest_start..path_end].starts_with("//") {
+            let authority_start = rest_start + 2;
+            let authority_end = input[authority_start..path_end]
+                .find('/')
+                .map_or(path_end, |i| authority_start + i);
+            let host_start = match input[authority_start..authority_end]
+                .find('@')
+            {
+                Some(i) => {
+                    spans.userinfo = Some(authority_start..authority_start + i);
+                    authority_start + i + 1
+                },
+                None => authority_start,
+            };
+            let host_port_string = &input[host_start..authority_end];
+            let host_end = host_port_string.rfind(']').unwrap_or(0);
+            match host_port_string[host_end..].find(':') {
+                Some(i) => {
+                    let colon = host_start + host_end + i;
+                    spans.host = Some(host_start..colon);
+                    spans.port = Some(colon + 1..authority_end);
+                },
+                None => spans.host = Some(host_start..authority_end),
+            }
+            spans.authority = Some(authority_start..authority_end);
+            spans.path = authority_end..path_end;
+        }
+        let fragment_start =
+            input[path_end..].find('#').map(|i| path_end + i + 1);
+        if input[path_end..].starts_with('?') {
+            spans.query = Some(
+                path_end + 1..fragment_start.map_or(input.len(), |i| i - 1),
+            );
+        }
+        spans.fragment = fragment_start.map(|i| i..input.len());
+        spans
+    }
+}
@@ -0,0 +1,60 @@
+/// This is a set of flags indicating which components of a URI are present,
+/// as returned by [`Uri::present_components`].  Flags may be combined with
+/// the `|` operator.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate rhymuri;
+/// use rhymuri::{
+///     Components,
+///     Uri,
+/// };
+///
+/// # fn main() -> Result<(), rhymuri::Error> {
+/// let components =
+///     Uri::parse("http://www.example.com/?")?.present_components();
+/// assert!(components.contains(Components::SCHEME | Components::QUERY));
+/// assert!(!components.contains(Components::FRAGMENT));
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Uri::present_components`]: struct.Uri.html#method.present_components
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Components(u8);
+
+impl Components {
+    /// The URI has an authority, even if it's empty, as in `file:///foo`.
+    pub const AUTHORITY: Self = Self(2);
+    /// The URI has a fragment, even if it's empty, as in `http://a/#`.
+    pub const FRAGMENT: Self = Self(16);
+    /// The URI has a non-empty path.  The `/` taken to be the path when the
+    /// path after an authority is empty, as in `http://a`, doesn't count.
+    pub const PATH: Self = Self(4);
+    /// The URI has a query, even if it's empty, as in `http://a/?`.
+    pub const QUERY: Self = Self(8);
+    /// The URI has a scheme.
+    pub const SCHEME: Self = Self(1);
+
+    /// Determine whether all the flags in `other` are also set in this set
+    /// of flags.
+    #[must_use = "you asked whether the flags are set, so check the answer"]
+    pub fn contains(
+        self,
+        other: Self,
+    ) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl std::ops::BitOr for Components {
+    type Output = Self;
+
+    fn bitor(
+        self,
+        rhs: Self,
+    ) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
@@ -4,11 +4,46 @@ use super::context::Context;
 /// crate generates.
 #[derive(Debug, Clone, thiserror::Error, PartialEq)]
 pub enum Error {
+    /// URI has a path which can't be expressed unambiguously alongside its
+    /// other components, such as a path beginning with `//` in a URI without
+    /// an authority.
+    #[error("path cannot be expressed unambiguously")]
+    AmbiguousPath,
+
     /// One or more components of the URI contains a non-UTF8 sequence,
     /// and so cannot be converted to a Rust string.
     #[error("URI contains non-UTF8 sequences")]
     CannotExpressAsUtf8(#[from] std::string::FromUtf8Error),
 
+    /// URI has components which, once percent-encoded characters are
+    /// decoded, add up to more bytes than allowed by the
+    /// [`max_decoded_len`][max_decoded_len] limit.
+    ///
+    /// [max_decoded_len]: struct.ParseLimits.html#structfield.max_decoded_len
+    #[error("decoded URI too large")]
+    DecodedTooLarge,
+
+    /// URI contains an IPv6 address with an embedded IPv4 address, such as
+    /// `[::ffff:1.2.3.4]`.  This is only reported when parsing with the
+    /// [`reject_embedded_ipv4`][reject_embedded_ipv4] option.
+    ///
+    /// [reject_embedded_ipv4]: struct.ParseOptions.html#structfield.reject_embedded_ipv4
+    #[error("IPv6 address with embedded IPv4 address not allowed")]
+    EmbeddedIpv4NotAllowed,
+
+    /// URI contains an authority with a port number but no host, such as
+    /// `//:8080`.  This is only reported when parsing with the
+    /// [`strict`](struct.ParseOptions.html#structfield.strict) option.
+    #[error("port number given without a host")]
+    EmptyAuthorityWithPort,
+
+    /// URI string is empty, or contains only whitespace.  This is only
+    /// reported by [`Uri::parse_non_empty`][parse_non_empty].
+    ///
+    /// [parse_non_empty]: struct.Uri.html#method.parse_non_empty
+    #[error("URI string is empty")]
+    EmptyInput,
+
     /// URI begins with an empty scheme, such as `://www.example.com`
     #[error("scheme expected but missing")]
     EmptyScheme,
@@ -20,7 +55,8 @@ pub enum Error {
     IllegalCharacter(Context),
 
     /// URI contains an incorrect percent encoding, such as
-    /// `http://www.example.com?foo=%GG`
+    /// `http://www.example.com?foo=%GG`, or a percent sign without two
+    /// hexadecimal digits after it, such as `http://www.example.com/%4`
     #[error("illegal percent encoding")]
     IllegalPercentEncoding,
 
@@ -30,10 +66,33 @@ pub enum Error {
     IllegalPortNumber(#[source] std::num::ParseIntError),
 
     /// URI contains an IPv4 address with one or more bad parts, such as
-    /// `http://[::ffff:1.2.3.256]/`
+    /// `http://[::ffff:1.2.3.256]/`, or, when parsing with the
+    /// [`strict`](struct.ParseOptions.html#structfield.strict) option, a
+    /// part with a leading zero, such as `http://1.2.3.04/`
     #[error("octet group expected")]
     InvalidDecimalOctet,
 
+    /// A URI was resolved with [`Uri::resolve_checked`] against a base
+    /// which has no scheme, and so is not an absolute URI
+    ///
+    /// [`Uri::resolve_checked`]: struct.Uri.html#method.resolve_checked
+    #[error("base URI is not absolute")]
+    NonAbsoluteBase,
+
+    /// URI has a scheme longer than allowed by the
+    /// [`max_scheme_len`][max_scheme_len] limit.
+    ///
+    /// [max_scheme_len]: struct.ParseLimits.html#structfield.max_scheme_len
+    #[error("scheme too long")]
+    SchemeTooLong,
+
+    /// A path given to [`Uri::set_path_checked`] has a segment containing a
+    /// slash (`/`), which would be ambiguous with a segment separator
+    ///
+    /// [`Uri::set_path_checked`]: struct.Uri.html#method.set_path_checked
+    #[error("path segment contains a slash")]
+    SlashInPathSegment,
+
     /// URI contains an IP address with missing parts, such as
     /// `http://[::ffff:1.2.3]/`
     #[error("too few address parts")]
@@ -44,6 +103,13 @@ pub enum Error {
     #[error("too many address parts")]
     TooManyAddressParts,
 
+    /// URI has a path with more segments than allowed by the
+    /// [`max_segments`][max_segments] limit.
+    ///
+    /// [max_segments]: struct.ParseLimits.html#structfield.max_segments
+    #[error("too many path segments")]
+    TooManySegments,
+
     /// URI contains an IPv6 address with too many digits, such as
     /// `http://[20001:db8:85a3::1]/`
     #[error("too many digits in IPv6 address part")]
@@ -58,4 +124,26 @@ pub enum Error {
     /// `http://[2001:db8:85a3::8a2e:0:]/`
     #[error("truncated host")]
     TruncatedHost,
+
+    /// URI has an authority, such as `blank` in `about://blank`, although
+    /// its scheme is one whose URIs never have one, such as `about`, `data`,
+    /// `javascript`, `mailto`, `tel`, or `urn`.  This is only reported when
+    /// parsing with the
+    /// [`strict`](struct.ParseOptions.html#structfield.strict) option.
+    #[error("authority not expected for scheme")]
+    UnexpectedAuthority,
+
+    /// URI has a scheme where a relative reference was expected, such as
+    /// `http://www.example.com/` given to [`RelativeReference::parse`].
+    ///
+    /// [`RelativeReference::parse`]: struct.RelativeReference.html#method.parse
+    #[error("scheme not expected in relative reference")]
+    UnexpectedScheme,
+
+    /// URI contains userinfo, such as `http://www.example.com@evil.com/`,
+    /// when parsing with the [`reject_userinfo`][reject_userinfo] option.
+    ///
+    /// [reject_userinfo]: struct.ParseOptions.html#structfield.reject_userinfo
+    #[error("userinfo not allowed")]
+    UserinfoNotAllowed,
 }
@@ -0,0 +1,16 @@
+/// This enumerates the three ways a fragment may appear in a URI, making the
+/// distinction between an absent fragment and an empty one explicit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FragmentState<'a> {
+    /// There is no fragment in the URI, such as in
+    /// `http://www.example.com/foo`.
+    Absent,
+
+    /// The fragment delimiter is present but nothing follows it, such as in
+    /// `http://www.example.com/foo#`.
+    Empty,
+
+    /// The fragment is present and not empty, such as `baz` in
+    /// `http://www.example.com/foo#baz`.
+    Present(&'a [u8]),
+}
@@ -68,19 +68,48 @@
 extern crate named_tuple;
 
 mod authority;
+mod cached_uri;
 mod character_classes;
 mod codec;
+mod component_spans;
+mod components;
 mod context;
 mod error;
+mod fragment_state;
+mod normalization;
+mod parse_error;
 mod parse_host_port;
+mod parse_limits;
+mod parse_options;
 mod percent_encoded_character_decoder;
+mod query_state;
+mod registered_schemes;
+mod relative_reference;
+mod scheme_type;
 mod uri;
+mod uri_key;
+mod uri_ref;
+mod uri_strings;
 mod validate_ipv4_address;
 mod validate_ipv6_address;
 
 pub use crate::{
     authority::Authority,
+    cached_uri::CachedUri,
+    component_spans::ComponentSpans,
+    components::Components,
     context::Context,
     error::Error,
+    fragment_state::FragmentState,
+    normalization::Normalization,
+    parse_error::ParseError,
+    parse_limits::ParseLimits,
+    parse_options::ParseOptions,
+    query_state::QueryState,
+    relative_reference::RelativeReference,
+    scheme_type::SchemeType,
     uri::Uri,
+    uri_key::UriKey,
+    uri_ref::UriRef,
+    uri_strings::UriStrings,
 };
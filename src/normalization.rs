@@ -0,0 +1,54 @@
+/// This is a set of flags selecting optional steps which
+/// [`Uri::normalize_with`] takes in addition to the normalization described
+/// in [RFC 3986](https://tools.ietf.org/html/rfc3986), which is always done.
+/// Flags may be combined with the `|` operator.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate rhymuri;
+/// use rhymuri::{
+///     Normalization,
+///     Uri,
+/// };
+///
+/// # fn main() -> Result<(), rhymuri::Error> {
+/// let mut uri = Uri::parse("/a//b/")?;
+/// uri.normalize_with(Normalization::COLLAPSE_SLASHES);
+/// assert_eq!("/a/b/", uri.path_to_string()?);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Uri::normalize_with`]: struct.Uri.html#method.normalize_with
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Normalization(u32);
+
+impl Normalization {
+    /// Remove empty segments from the interior of the path, so that
+    /// `/a//b/` becomes `/a/b/`.  A slash at the very beginning or end of
+    /// the path is kept.  This is not done by default, since RFC 3986 treats
+    /// these paths as different.
+    pub const COLLAPSE_SLASHES: Self = Self(1);
+
+    /// Determine whether all the flags in `other` are also set in this set
+    /// of flags.
+    #[must_use = "you asked whether the flags are set, so check the answer"]
+    pub fn contains(
+        self,
+        other: Self,
+    ) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl std::ops::BitOr for Normalization {
+    type Output = Self;
+
+    fn bitor(
+        self,
+        rhs: Self,
+    ) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
@@ -0,0 +1,381 @@
+#![warn(clippy::pedantic)]
+
+use super::{
+    context::Context,
+    error::Error,
+};
+
+/// This bundles an [`Error`] returned by [`Uri::parse_detailed`] with where
+/// in the URI string the problem was found: the [`Context`], and the byte
+/// offset of the offending character, or of the start of the part of the
+/// string which couldn't be parsed (or of its end, if it was cut short),
+/// when the problem can't be pinned on a single character.
+///
+/// When formatted with `Display`, the error is followed by the URI string,
+/// with a caret (`^`) pointing at the problem underneath it.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate rhymuri;
+/// use rhymuri::{
+///     Context,
+///     Error,
+///     Uri,
+/// };
+///
+/// let error =
+///     Uri::parse_detailed("http://www.example.com/foo^bar").unwrap_err();
+/// assert_eq!(&Error::IllegalCharacter(Context::Path), error.error());
+/// assert_eq!(Context::Path, error.context());
+/// assert_eq!(26, error.offset());
+/// assert_eq!(
+///     "illegal character in path at offset 26\n\
+///      http://www.example.com/foo^bar\n\
+///      \x20                         ^",
+///     error.to_string()
+/// );
+/// ```
+///
+/// [`Context`]: enum.Context.html
+/// [`Error`]: enum.Error.html
+/// [`Uri::parse_detailed`]: struct.Uri.html#method.parse_detailed
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    error: Error,
+    context: Context,
+    offset: usize,
+    input: String,
+}
+
+impl ParseError {
+    /// Return the part of the URI where the problem was found.
+    #[must_use = "why ask where the problem is and then ignore the answer?"]
+    pub fn context(&self) -> Context {
+        self.context
+    }
+
+    /// Borrow the error which occurred.
+    #[must_use = "why borrow the error if you're not going to look at it?"]
+    pub fn error(&self) -> &Error {
+        &self.error
+    }
+
+    /// Borrow the URI string which couldn't be parsed.
+    #[must_use = "why borrow the input if you're not going to look at it?"]
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// Consume the value and return just the error which occurred.
+    #[must_use = "you unwrapped the error; don't drop it on the floor"]
+    pub fn into_error(self) -> Error {
+        self.error
+    }
+
+    // Wrap the given error, which occurred while parsing the given input,
+    // at the given byte offset, in the given part of the URI.
+    pub(crate) fn new<T>(
+        input: T,
+        error: Error,
+        offset: usize,
+        context: Context,
+    ) -> Self
+    where
+        T: Into<String>,
+    {
+        Self {
+            error,
+            context,
+            offset,
+            input: input.into(),
+        }
+    }
+
+    /// Return the byte offset in the URI string where the problem was found.
+    #[must_use = "why ask where the problem is and then ignore the answer?"]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        let column = self.input[..self.offset].chars().count();
+        writeln!(f, "{} at offset {}", self.error, self.offset)?;
+        writeln!(f, "{}", self.input)?;
+        write!(f, "{:column$}^", "", column = column)
+    }
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::uri::Uri;
+
+    #[test]
+    // NOTE: This lint is disabled because it's triggered inside the
+    // `named_tuple!` macro expansion.
+    #[allow(clippy::from_over_into)]
+    fn authority_error_offsets() {
+        named_tuple!(
+            struct TestVector {
+                input: &'static str,
+                error: Error,
+                offset: usize,
+                context: Context,
+            }
+        );
+        let test_vectors: &[TestVector] = &[
+            (
+                "http://b^b@a/",
+                Error::IllegalCharacter(Context::Userinfo),
+                8,
+                Context::Userinfo,
+            )
+                .into(),
+            (
+                "http://a^b:80/",
+                Error::IllegalCharacter(Context::Host),
+                8,
+                Context::Host,
+            )
+                .into(),
+            (
+                "http://[::1]x/",
+                Error::IllegalCharacter(Context::Host),
+                12,
+                Context::Host,
+            )
+                .into(),
+            (
+                "http://a:8x/",
+                Error::IllegalPortNumber("8x".parse::<u16>().unwrap_err()),
+                9,
+                Context::Host,
+            )
+                .into(),
+            (
+                "http://[::1]:99999/",
+                Error::IllegalPortNumber("99999".parse::<u16>().unwrap_err()),
+                13,
+                Context::Host,
+            )
+                .into(),
+            ("http://a%4G/", Error::IllegalPercentEncoding, 8, Context::Host)
+                .into(),
+        ];
+        for test_vector in test_vectors {
+            let error = Uri::parse_detailed(test_vector.input()).unwrap_err();
+            assert_eq!(
+                test_vector.error(),
+                error.error(),
+                "{}",
+                test_vector.input()
+            );
+            assert_eq!(
+                *test_vector.offset(),
+                error.offset(),
+                "{}",
+                test_vector.input()
+            );
+            assert_eq!(
+                *test_vector.context(),
+                error.context(),
+                "{}",
+                test_vector.input()
+            );
+        }
+    }
+
+    #[test]
+    // NOTE: This lint is disabled because it's triggered inside the
+    // `named_tuple!` macro expansion.
+    #[allow(clippy::from_over_into)]
+    fn ip_literal_error_offsets() {
+        named_tuple!(
+            struct TestVector {
+                input: &'static str,
+                error: Error,
+                offset: usize,
+                context: Context,
+            }
+        );
+        let test_vectors: &[TestVector] = &[
+            (
+                "http://[::fxff:1.2.3.4]/",
+                Error::IllegalCharacter(Context::Ipv6Address),
+                11,
+                Context::Ipv6Address,
+            )
+                .into(),
+            (
+                "http://[::ffff:1.2.x.4]/",
+                Error::IllegalCharacter(Context::Ipv4Address),
+                19,
+                Context::Ipv4Address,
+            )
+                .into(),
+            (
+                "http://[v7x.aB]/",
+                Error::IllegalCharacter(Context::IpvFuture),
+                10,
+                Context::IpvFuture,
+            )
+                .into(),
+            (
+                "http://[v7.a^B]/",
+                Error::IllegalCharacter(Context::IpvFuture),
+                12,
+                Context::IpvFuture,
+            )
+                .into(),
+            (
+                "http://[::ffff:1.2.3.256]/",
+                Error::InvalidDecimalOctet,
+                21,
+                Context::Host,
+            )
+                .into(),
+            ("http://[12345::1]/", Error::TooManyDigits, 12, Context::Host)
+                .into(),
+            ("http://[::1/", Error::TruncatedHost, 11, Context::Host).into(),
+        ];
+        for test_vector in test_vectors {
+            let error = Uri::parse_detailed(test_vector.input()).unwrap_err();
+            assert_eq!(
+                test_vector.error(),
+                error.error(),
+                "{}",
+                test_vector.input()
+            );
+            assert_eq!(
+                *test_vector.offset(),
+                error.offset(),
+                "{}",
+                test_vector.input()
+            );
+            assert_eq!(
+                *test_vector.context(),
+                error.context(),
+                "{}",
+                test_vector.input()
+            );
+        }
+    }
+
+    #[test]
+    // NOTE: This lint is disabled because it's triggered inside the
+    // `named_tuple!` macro expansion.
+    #[allow(clippy::from_over_into)]
+    fn other_error_offsets() {
+        named_tuple!(
+            struct TestVector {
+                input: &'static str,
+                error: Error,
+                offset: usize,
+                context: Context,
+            }
+        );
+        let test_vectors: &[TestVector] = &[
+            (
+                "/a/b c",
+                Error::IllegalCharacter(Context::Path),
+                4,
+                Context::Path,
+            )
+                .into(),
+            (
+                "/a?b^c#d",
+                Error::IllegalCharacter(Context::Query),
+                4,
+                Context::Query,
+            )
+                .into(),
+            (
+                "/a?b#c^d",
+                Error::IllegalCharacter(Context::Fragment),
+                6,
+                Context::Fragment,
+            )
+                .into(),
+            ("/a?b%GGc", Error::IllegalPercentEncoding, 4, Context::Query)
+                .into(),
+            ("/a%2G", Error::IllegalPercentEncoding, 2, Context::Path).into(),
+            ("/a?b#c%", Error::IllegalPercentEncoding, 6, Context::Fragment)
+                .into(),
+        ];
+        for test_vector in test_vectors {
+            let error = Uri::parse_detailed(test_vector.input()).unwrap_err();
+            assert_eq!(
+                test_vector.error(),
+                error.error(),
+                "{}",
+                test_vector.input()
+            );
+            assert_eq!(
+                *test_vector.offset(),
+                error.offset(),
+                "{}",
+                test_vector.input()
+            );
+            assert_eq!(
+                *test_vector.context(),
+                error.context(),
+                "{}",
+                test_vector.input()
+            );
+        }
+    }
+
+    #[test]
+    fn display_points_at_column() {
+        let error = ParseError::new(
+            "http://\u{e9}x.com/a b",
+            Error::IllegalCharacter(Context::Path),
+            16,
+            Context::Path,
+        );
+        assert_eq!(16, error.offset());
+        assert_eq!(
+            "illegal character in path at offset 16\n\
+             http://\u{e9}x.com/a b\n               ^",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn scheme_error_column() {
+        let error = Uri::parse_detailed("h@ttp://a").unwrap_err();
+        assert_eq!(&Error::IllegalCharacter(Context::Scheme), error.error());
+        assert_eq!(Context::Scheme, error.context());
+        assert_eq!(1, error.offset());
+        assert_eq!(
+            "illegal character in scheme at offset 1\n\
+             h@ttp://a\n \
+             ^",
+            error.to_string()
+        );
+        let error = Uri::parse_detailed("htt\u{e9}p://a").unwrap_err();
+        assert_eq!(Context::Scheme, error.context());
+        assert_eq!(3, error.offset());
+        let error = Uri::parse_detailed("ht!tp://a/").unwrap_err();
+        assert_eq!(2, error.offset());
+        let error = Uri::parse_detailed("1http://a/").unwrap_err();
+        assert_eq!(0, error.offset());
+        let error = Uri::parse_detailed("://a/").unwrap_err();
+        assert_eq!(&Error::EmptyScheme, error.error());
+        assert_eq!(Context::Scheme, error.context());
+        assert_eq!(0, error.offset());
+    }
+}
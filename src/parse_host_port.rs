@@ -6,39 +6,70 @@ use super::{
         IPV_FUTURE_LAST_PART,
         REG_NAME_NOT_PCT_ENCODED,
     },
+    codec::DecodeBudget,
     context::Context,
     error::Error,
     percent_encoded_character_decoder::PercentEncodedCharacterDecoder,
-    validate_ipv6_address::validate_ipv6_address,
+    validate_ipv6_address::validate_ipv6_address_noting_offset,
 };
 
-struct Shared {
+struct Shared<'a> {
+    budget: &'a mut DecodeBudget,
     host: Vec<u8>,
     host_is_reg_name: bool,
     ipv6_address: String,
+    ipv6_address_start: usize,
     pec_decoder: PercentEncodedCharacterDecoder,
+    pec_start: usize,
+    port_start: usize,
     port_string: String,
 }
 
-enum State {
-    NotIpLiteral(Shared),
-    PercentEncodedCharacter(Shared),
-    Ipv6Address(Shared),
-    IpvFutureNumber(Shared),
-    IpvFutureBody(Shared),
-    GarbageCheck(Shared),
-    Port(Shared),
+impl Shared<'_> {
+    // Add the given byte, decoded from the character at the given byte
+    // offset in the string being parsed, to the host, spending the budget
+    // on it.
+    fn push_host(
+        &mut self,
+        ci: u8,
+        offset: usize,
+    ) -> Result<(), Error> {
+        self.budget.spend(offset, Context::Host)?;
+        self.host.push(ci);
+        Ok(())
+    }
+}
+
+enum State<'a> {
+    NotIpLiteral(Shared<'a>),
+    PercentEncodedCharacter(Shared<'a>),
+    Ipv6Address(Shared<'a>),
+    IpvFutureNumber(Shared<'a>),
+    IpvFutureBodyStart(Shared<'a>),
+    IpvFutureBody(Shared<'a>),
+    GarbageCheck(Shared<'a>),
+    Port(Shared<'a>),
 }
 
-impl State {
-    fn finalize(self) -> Result<(Vec<u8>, Option<u16>), Error> {
+impl<'a> State<'a> {
+    // Finish parsing, given the byte offset of the end of the host and port
+    // in the string being parsed.
+    fn finalize(
+        self,
+        end: usize,
+    ) -> Result<(Vec<u8>, Option<u16>), Error> {
         match self {
-            Self::PercentEncodedCharacter(_)
-            | Self::Ipv6Address(_)
-            | Self::IpvFutureNumber(_)
-            | Self::IpvFutureBody(_) => {
+            Self::PercentEncodedCharacter(state)
+            | Self::Ipv6Address(state)
+            | Self::IpvFutureNumber(state)
+            | Self::IpvFutureBodyStart(state)
+            | Self::IpvFutureBody(state) => {
                 // truncated or ended early
-                Err(Error::TruncatedHost)
+                Err(state.budget.fail_at(
+                    end,
+                    Context::Host,
+                    Error::TruncatedHost,
+                ))
             },
             Self::NotIpLiteral(state)
             | Self::GarbageCheck(state)
@@ -53,7 +84,11 @@ impl State {
                     match state.port_string.parse::<u16>() {
                         Ok(port) => Some(port),
                         Err(error) => {
-                            return Err(Error::IllegalPortNumber(error));
+                            return Err(state.budget.fail_at(
+                                state.port_start,
+                                Context::Host,
+                                Error::IllegalPortNumber(error),
+                            ));
                         },
                     }
                 };
@@ -62,66 +97,91 @@ impl State {
         }
     }
 
-    fn new(host_port_string: &str) -> (Self, &str) {
+    fn new<'b>(
+        host_port_string: &'b str,
+        offset: usize,
+        budget: &'a mut DecodeBudget,
+    ) -> Result<(Self, &'b str), Error> {
         let mut shared = Shared {
+            budget,
             host: Vec::<u8>::new(),
             host_is_reg_name: false,
             ipv6_address: String::new(),
+            ipv6_address_start: offset + 1,
             pec_decoder: PercentEncodedCharacterDecoder::new(),
+            pec_start: offset,
+            port_start: offset,
             port_string: String::new(),
         };
         let mut host_port_string = host_port_string;
-        if host_port_string.starts_with("[v") {
+        if host_port_string.starts_with("[v")
+            || host_port_string.starts_with("[V")
+        {
+            shared.push_host(host_port_string.as_bytes()[1], offset + 1)?;
             host_port_string = &host_port_string[2..];
-            shared.host.push(b'v');
-            (Self::IpvFutureNumber(shared), host_port_string)
+            Ok((Self::IpvFutureNumber(shared), host_port_string))
         } else if host_port_string.starts_with('[') {
             host_port_string = &host_port_string[1..];
-            (Self::Ipv6Address(shared), host_port_string)
+            Ok((Self::Ipv6Address(shared), host_port_string))
         } else {
             shared.host_is_reg_name = true;
-            (Self::NotIpLiteral(shared), host_port_string)
+            Ok((Self::NotIpLiteral(shared), host_port_string))
         }
     }
 
+    // Take the next character of the string being parsed, which is at the
+    // given byte offset in it.
     fn next(
         self,
+        i: usize,
         c: char,
     ) -> Result<Self, Error> {
         match self {
-            Self::NotIpLiteral(state) => Self::next_not_ip_literal(state, c),
+            Self::NotIpLiteral(state) => Self::next_not_ip_literal(state, i, c),
             Self::PercentEncodedCharacter(state) => {
                 Self::next_percent_encoded_character(state, c)
             },
             Self::Ipv6Address(state) => Self::next_ipv6_address(state, c),
             Self::IpvFutureNumber(state) => {
-                Self::next_ipv_future_number(state, c)
+                Self::next_ipv_future_number(state, i, c)
             },
-            Self::IpvFutureBody(state) => Self::next_ipv_future_body(state, c),
-            Self::GarbageCheck(state) => Self::next_garbage_check(state, c),
+            Self::IpvFutureBodyStart(state) => {
+                Self::next_ipv_future_body_start(state, i, c)
+            },
+            Self::IpvFutureBody(state) => {
+                Self::next_ipv_future_body(state, i, c)
+            },
+            Self::GarbageCheck(state) => Self::next_garbage_check(state, i, c),
             Self::Port(state) => Ok(Self::next_port(state, c)),
         }
     }
 
     fn next_not_ip_literal(
-        state: Shared,
+        state: Shared<'a>,
+        i: usize,
         c: char,
     ) -> Result<Self, Error> {
         let mut state = state;
         if c == '%' {
+            state.pec_start = i;
             Ok(Self::PercentEncodedCharacter(state))
         } else if c == ':' {
+            state.port_start = i + 1;
             Ok(Self::Port(state))
         } else if REG_NAME_NOT_PCT_ENCODED.contains(&c) {
-            state.host.push(u8::try_from(c as u32).unwrap());
+            state.push_host(u8::try_from(c as u32).unwrap(), i)?;
             Ok(Self::NotIpLiteral(state))
         } else {
-            Err(Error::IllegalCharacter(Context::Host))
+            Err(state.budget.fail_at(
+                i,
+                Context::Host,
+                Error::IllegalCharacter(Context::Host),
+            ))
         }
     }
 
     fn next_percent_encoded_character(
-        state: Shared,
+        state: Shared<'a>,
         c: char,
     ) -> Result<Self, Error> {
         let mut state = state;
@@ -130,8 +190,11 @@ impl State {
         // `state`, preventing it from being used to construct the default
         // value.
         #[allow(clippy::option_if_let_else)]
-        if let Some(ci) = state.pec_decoder.next(c)? {
-            state.host.push(ci);
+        let pec_start = state.pec_start;
+        if let Some(ci) = state.pec_decoder.next(c).map_err(|error| {
+            state.budget.fail_at(pec_start, Context::Host, error)
+        })? {
+            state.push_host(ci, pec_start)?;
             Ok(Self::NotIpLiteral(state))
         } else {
             Ok(Self::PercentEncodedCharacter(state))
@@ -139,17 +202,28 @@ impl State {
     }
 
     fn next_ipv6_address(
-        state: Shared,
+        state: Shared<'a>,
         c: char,
     ) -> Result<Self, Error> {
         let mut state = state;
         if c == ']' {
-            validate_ipv6_address(&state.ipv6_address)?;
-            state.host = state
-                .ipv6_address
-                .chars()
-                .map(|c| u8::try_from(c as u32).unwrap())
-                .collect();
+            let ipv6_address_start = state.ipv6_address_start;
+            validate_ipv6_address_noting_offset(&state.ipv6_address).map_err(
+                |(error, i)| {
+                    let context = match error {
+                        Error::IllegalCharacter(context) => context,
+                        _ => Context::Host,
+                    };
+                    state.budget.fail_at(ipv6_address_start + i, context, error)
+                },
+            )?;
+            let ipv6_address = std::mem::take(&mut state.ipv6_address);
+            for (i, c) in ipv6_address.char_indices() {
+                state.push_host(
+                    u8::try_from(c as u32).unwrap(),
+                    ipv6_address_start + i,
+                )?;
+            }
             Ok(Self::GarbageCheck(state))
         } else {
             state.ipv6_address.push(c);
@@ -158,53 +232,91 @@ impl State {
     }
 
     fn next_ipv_future_number(
-        state: Shared,
+        state: Shared<'a>,
+        i: usize,
         c: char,
     ) -> Result<Self, Error> {
         let mut state = state;
         if c == '.' {
-            state.host.push(b'.');
-            Ok(Self::IpvFutureBody(state))
+            // the version number must have at least one digit
+            if state.host.len() < 2 {
+                return Err(state.budget.fail_at(
+                    i,
+                    Context::Host,
+                    Error::TruncatedHost,
+                ));
+            }
+            state.push_host(b'.', i)?;
+            Ok(Self::IpvFutureBodyStart(state))
         } else if c == ']' {
-            Err(Error::TruncatedHost)
+            Err(state.budget.fail_at(i, Context::Host, Error::TruncatedHost))
         } else if HEXDIG.contains(&c) {
-            state.host.push(u8::try_from(c as u32).unwrap());
+            state.push_host(u8::try_from(c as u32).unwrap(), i)?;
             Ok(Self::IpvFutureNumber(state))
         } else {
-            Err(Error::IllegalCharacter(Context::IpvFuture))
+            Err(state.budget.fail_at(
+                i,
+                Context::IpvFuture,
+                Error::IllegalCharacter(Context::IpvFuture),
+            ))
+        }
+    }
+
+    fn next_ipv_future_body_start(
+        state: Shared<'a>,
+        i: usize,
+        c: char,
+    ) -> Result<Self, Error> {
+        // the body must have at least one character
+        if c == ']' {
+            Err(state.budget.fail_at(i, Context::Host, Error::TruncatedHost))
+        } else {
+            Self::next_ipv_future_body(state, i, c)
         }
     }
 
     fn next_ipv_future_body(
-        state: Shared,
+        state: Shared<'a>,
+        i: usize,
         c: char,
     ) -> Result<Self, Error> {
         let mut state = state;
         if c == ']' {
             Ok(Self::GarbageCheck(state))
         } else if IPV_FUTURE_LAST_PART.contains(&c) {
-            state.host.push(u8::try_from(c as u32).unwrap());
+            state.push_host(u8::try_from(c as u32).unwrap(), i)?;
             Ok(Self::IpvFutureBody(state))
         } else {
-            Err(Error::IllegalCharacter(Context::IpvFuture))
+            Err(state.budget.fail_at(
+                i,
+                Context::IpvFuture,
+                Error::IllegalCharacter(Context::IpvFuture),
+            ))
         }
     }
 
     fn next_garbage_check(
-        state: Shared,
+        state: Shared<'a>,
+        i: usize,
         c: char,
     ) -> Result<Self, Error> {
+        let mut state = state;
         // illegal to have anything else, unless it's a colon,
         // in which case it's a port delimiter
         if c == ':' {
+            state.port_start = i + 1;
             Ok(Self::Port(state))
         } else {
-            Err(Error::IllegalCharacter(Context::Host))
+            Err(state.budget.fail_at(
+                i,
+                Context::Host,
+                Error::IllegalCharacter(Context::Host),
+            ))
         }
     }
 
     fn next_port(
-        state: Shared,
+        state: Shared<'a>,
         c: char,
     ) -> Self {
         let mut state = state;
@@ -213,14 +325,19 @@ impl State {
     }
 }
 
-pub fn parse_host_port<T>(
-    host_port_string: T
-) -> Result<(Vec<u8>, Option<u16>), Error>
-where
-    T: AsRef<str>,
-{
-    let (machine, host_port_string) = State::new(host_port_string.as_ref());
-    host_port_string.chars().try_fold(machine, State::next)?.finalize()
+// Parse the given host and port, which start at the given byte offset in
+// the string being parsed, spending the given budget on each byte decoded
+// from the host.
+pub fn parse_host_port(
+    host_port_string: &str,
+    offset: usize,
+    budget: &mut DecodeBudget,
+) -> Result<(Vec<u8>, Option<u16>), Error> {
+    let (machine, rest) = State::new(host_port_string, offset, budget)?;
+    let rest_start = offset + host_port_string.len() - rest.len();
+    rest.char_indices()
+        .try_fold(machine, |machine, (i, c)| machine.next(rest_start + i, c))?
+        .finalize(offset + host_port_string.len())
 }
 
 #[cfg(test)]
@@ -228,6 +345,16 @@ mod tests {
 
     use super::*;
 
+    fn parse_host_port(
+        host_port_string: &str
+    ) -> Result<(Vec<u8>, Option<u16>), Error> {
+        super::parse_host_port(
+            host_port_string,
+            0,
+            &mut DecodeBudget::unlimited(),
+        )
+    }
+
     #[test]
     fn non_empty_port_number() {
         let result = parse_host_port("www.example.com:8080");
@@ -300,6 +427,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn ipv_future() {
+        let result = parse_host_port("[v7.aB]:8080");
+        assert!(result.is_ok());
+        let (host, port) = result.unwrap();
+        assert_eq!(b"v7.aB", &host[..]);
+        assert_eq!(Some(8080), port);
+        assert_eq!(
+            Err(Error::IllegalCharacter(Context::Host)),
+            parse_host_port("[v7.aB]extra")
+        );
+        assert_eq!(
+            Err(Error::IllegalCharacter(Context::Host)),
+            parse_host_port("[v7.aB]]")
+        );
+        let test_vectors = ["[v7.]", "[v.aB]", "[v]", "[v7]", "[v7.aB"];
+        for test_vector in &test_vectors {
+            assert_eq!(
+                Err(Error::TruncatedHost),
+                parse_host_port(test_vector),
+                "{}",
+                test_vector
+            );
+        }
+    }
+
     #[test]
     fn ipv6_address_with_ipv4_part_missing_bracket() {
         assert!(matches!(
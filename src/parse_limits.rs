@@ -0,0 +1,49 @@
+/// This holds limits on the size of the URI strings accepted by
+/// [`Uri::parse_with_limits`], which protect against pathological inputs
+/// that would otherwise take a lot of memory to parse.  Each limit is `None`
+/// by default, which means there is no limit.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate rhymuri;
+/// use rhymuri::{
+///     Error,
+///     ParseLimits,
+///     Uri,
+/// };
+///
+/// let limits = ParseLimits {
+///     max_segments: Some(3),
+///     ..ParseLimits::default()
+/// };
+/// assert!(Uri::parse_with_limits("/a/b", limits).is_ok());
+/// assert_eq!(
+///     Err(Error::TooManySegments),
+///     Uri::parse_with_limits("/a/b/c", limits)
+/// );
+/// ```
+///
+/// [`Uri::parse_with_limits`]: struct.Uri.html#method.parse_with_limits
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ParseLimits {
+    /// The largest number of segments allowed in the path, counting the
+    /// empty segment before the first slash of an absolute path, so that
+    /// `/a/b` has three segments.
+    pub max_segments: Option<usize>,
+
+    /// The longest scheme allowed, in characters, not counting the colon
+    /// which follows it.  A scheme longer than this is almost certainly a
+    /// sign of malformed input.
+    pub max_scheme_len: Option<usize>,
+
+    /// The largest total number of bytes allowed in the components of the
+    /// URI after percent-encoded characters are decoded, adding up the
+    /// userinfo, host, path segments, query and fragment.  This caps how
+    /// much memory the parsed URI holds, however it was encoded.  Parsing
+    /// stops as soon as the limit is passed, and
+    /// [`Uri::parse_detailed_with_options`] reports where that happened.
+    ///
+    /// [`Uri::parse_detailed_with_options`]: struct.Uri.html#method.parse_detailed_with_options
+    pub max_decoded_len: Option<usize>,
+}
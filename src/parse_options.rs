@@ -0,0 +1,86 @@
+use super::parse_limits::ParseLimits;
+
+/// This holds the options which control how URI strings are interpreted by
+/// [`Uri::parse_with_options`] and [`Authority::parse_with_options`].  The
+/// default options are the ones used by [`Uri::parse`] and
+/// [`Authority::parse`].
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate rhymuri;
+/// use rhymuri::{
+///     ParseOptions,
+///     Uri,
+/// };
+///
+/// let options = ParseOptions {
+///     strict: true,
+///     ..ParseOptions::default()
+/// };
+/// assert!(Uri::parse("//:8080").is_ok());
+/// assert!(Uri::parse_with_options("//:8080", options).is_err());
+/// ```
+///
+/// [`Authority::parse`]: struct.Authority.html#method.parse
+/// [`Authority::parse_with_options`]: struct.Authority.html#method.parse_with_options
+/// [`Uri::parse`]: struct.Uri.html#method.parse
+/// [`Uri::parse_with_options`]: struct.Uri.html#method.parse_with_options
+// NOTE: This lint is disabled because the options are independent of each
+// other, so there are no states to collapse into enums.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ParseOptions {
+    /// Reject URI strings which are syntactically valid but almost certainly
+    /// a mistake, or open to misinterpretation, such as:
+    /// * a port number without a host (`//:8080`)
+    /// * an IPv4 address with leading zeros in an octet (`1.2.3.04`), which
+    ///   some resolvers would interpret as octal, or with a hexadecimal octet
+    ///   (`0x7f.0.0.1`)
+    /// * an authority after a scheme whose URIs never have one
+    ///   (`about://blank`)
+    pub strict: bool,
+
+    /// Reject URI strings containing userinfo, such as
+    /// `http://www.example.com@evil.com/`, which can be used to make a link
+    /// appear to lead somewhere other than where it actually does.
+    pub reject_userinfo: bool,
+
+    /// Reject URI strings whose host is an IPv6 address with an embedded
+    /// IPv4 address, such as `[::ffff:1.2.3.4]`, so that each IPv6 address
+    /// has only the one (hexadecimal) representation.
+    pub reject_embedded_ipv4: bool,
+
+    /// Keep a copy of the path exactly as it appears in the URI string,
+    /// including its original percent-encoding, so that it can be retrieved
+    /// with [`Uri::raw_path`].
+    ///
+    /// [`Uri::raw_path`]: struct.Uri.html#method.raw_path
+    pub preserve_raw_path: bool,
+
+    /// Keep an empty path following an authority, as in
+    /// `http://www.example.com`, distinct from a slash-only path, as in
+    /// `http://www.example.com/`, rather than treating the two as
+    /// equivalent.  See [`Uri::path_is_rootless`].
+    ///
+    /// [`Uri::path_is_rootless`]: struct.Uri.html#method.path_is_rootless
+    pub preserve_empty_path: bool,
+
+    /// Keep the scheme exactly as it appears in the URI string, as in `HTTP`
+    /// for `HTTP://www.example.com/`, rather than making it lowercase.  The
+    /// characters of the scheme are still checked.  Schemes are recognized
+    /// without regard to case (for example, by [`Uri::scheme_type`]), but
+    /// URIs are compared as they are, so a URI parsed with this option may
+    /// not equal the same URI parsed without it until it's
+    /// [normalized][`Uri::normalize`].
+    ///
+    /// [`Uri::normalize`]: struct.Uri.html#method.normalize
+    /// [`Uri::scheme_type`]: struct.Uri.html#method.scheme_type
+    pub preserve_scheme_case: bool,
+
+    /// Limits on the size of the URI string, which protect against
+    /// pathological inputs.  See [`ParseLimits`] for details.
+    ///
+    /// [`ParseLimits`]: struct.ParseLimits.html
+    pub limits: ParseLimits,
+}
@@ -0,0 +1,16 @@
+/// This enumerates the three ways a query may appear in a URI, making the
+/// distinction between an absent query and an empty one explicit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QueryState<'a> {
+    /// There is no query in the URI, such as in
+    /// `http://www.example.com/foo`.
+    Absent,
+
+    /// The query delimiter is present but nothing follows it, such as in
+    /// `http://www.example.com/foo?`.
+    Empty,
+
+    /// The query is present and not empty, such as `bar` in
+    /// `http://www.example.com/foo?bar`.
+    Present(&'a [u8]),
+}
@@ -0,0 +1,94 @@
+use once_cell::sync::Lazy;
+use std::collections::HashSet;
+
+// This is the set of commonly-used schemes taken from the IANA "Uniform
+// Resource Identifier (URI) Schemes" registry
+// (https://www.iana.org/assignments/uri-schemes/uri-schemes.xhtml).  It's not
+// the whole registry, but it covers the schemes most likely to be seen, so
+// that a scheme missing from it is worth a second look.
+pub static REGISTERED_SCHEMES: Lazy<HashSet<&'static str>> = Lazy::new(|| {
+    [
+        "about",
+        "acap",
+        "acct",
+        "attachment",
+        "bitcoin",
+        "blob",
+        "cap",
+        "cid",
+        "coap",
+        "coaps",
+        "crid",
+        "data",
+        "dav",
+        "dict",
+        "dns",
+        "file",
+        "ftp",
+        "geo",
+        "go",
+        "gopher",
+        "h323",
+        "http",
+        "https",
+        "iax",
+        "icap",
+        "im",
+        "imap",
+        "info",
+        "ipp",
+        "ipps",
+        "iris",
+        "jabber",
+        "ldap",
+        "ldaps",
+        "magnet",
+        "mailto",
+        "mid",
+        "msrp",
+        "msrps",
+        "mtqp",
+        "mupdate",
+        "news",
+        "nfs",
+        "ni",
+        "nih",
+        "nntp",
+        "opaquelocktoken",
+        "pkcs11",
+        "pop",
+        "pres",
+        "redis",
+        "rediss",
+        "rsync",
+        "rtsp",
+        "rtsps",
+        "sftp",
+        "sip",
+        "sips",
+        "sms",
+        "snmp",
+        "soap.beep",
+        "soap.beeps",
+        "ssh",
+        "stun",
+        "stuns",
+        "tag",
+        "tel",
+        "telnet",
+        "tftp",
+        "tn3270",
+        "turn",
+        "turns",
+        "tv",
+        "urn",
+        "vemmi",
+        "vnc",
+        "ws",
+        "wss",
+        "xmpp",
+    ]
+    .iter()
+    .copied()
+    .collect()
+});
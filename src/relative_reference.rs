@@ -0,0 +1,129 @@
+#![warn(clippy::pedantic)]
+
+use std::convert::TryFrom;
+
+use super::{
+    error::Error,
+    uri::Uri,
+};
+
+/// This wraps a [`Uri`] which is known to be a relative reference, that is,
+/// one without a scheme.  Keeping relative references in their own type
+/// prevents them from being mixed up with the base URIs against which they
+/// are resolved.  Use [`Uri::resolve_ref`] to resolve one.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate rhymuri;
+/// use rhymuri::{
+///     RelativeReference,
+///     Uri,
+/// };
+///
+/// # fn main() -> Result<(), rhymuri::Error> {
+/// let base = Uri::parse("http://a/b/c/d;p?q")?;
+/// let relative_reference = RelativeReference::parse("../g")?;
+/// assert_eq!(
+///     "http://a/b/g",
+///     base.resolve_ref(&relative_reference).to_string()
+/// );
+/// assert!(RelativeReference::parse("http://a/g").is_err());
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Uri`]: struct.Uri.html
+/// [`Uri::resolve_ref`]: struct.Uri.html#method.resolve_ref
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RelativeReference(Uri);
+
+impl RelativeReference {
+    /// Borrow the relative reference as a [`Uri`](struct.Uri.html).
+    #[must_use = "why borrow the URI if you're not going to look at it?"]
+    pub fn as_uri(&self) -> &Uri {
+        &self.0
+    }
+
+    /// Consume the relative reference and return the
+    /// [`Uri`](struct.Uri.html) it wraps.
+    #[must_use = "you unwrapped the URI; don't drop it on the floor"]
+    pub fn into_uri(self) -> Uri {
+        self.0
+    }
+
+    /// Interpret the given string as a relative reference.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::UnexpectedScheme`][UnexpectedScheme] is returned if the
+    /// string has a scheme.  Otherwise, the string is rejected for the same
+    /// reasons as by [`Uri::parse`].
+    ///
+    /// [UnexpectedScheme]: enum.Error.html#variant.UnexpectedScheme
+    /// [`Uri::parse`]: struct.Uri.html#method.parse
+    #[must_use = "you parsed it; don't you want the results?"]
+    pub fn parse<T>(relative_reference_string: T) -> Result<Self, Error>
+    where
+        T: AsRef<str>,
+    {
+        Self::try_from(Uri::parse(relative_reference_string)?)
+    }
+}
+
+impl std::fmt::Display for RelativeReference {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl TryFrom<Uri> for RelativeReference {
+    type Error = Error;
+
+    fn try_from(uri: Uri) -> Result<Self, Self::Error> {
+        if uri.is_relative_reference() {
+            Ok(Self(uri))
+        } else {
+            Err(Error::UnexpectedScheme)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            Err(Error::UnexpectedScheme),
+            RelativeReference::parse("http://a")
+        );
+        let test_vectors = ["../x", "//a/b", "?q", "#f", ""];
+        for test_vector in &test_vectors {
+            let relative_reference = RelativeReference::parse(test_vector);
+            assert!(relative_reference.is_ok(), "{}", test_vector);
+            let relative_reference = relative_reference.unwrap();
+            assert_eq!(*test_vector, relative_reference.to_string());
+            assert_eq!(
+                Uri::parse(test_vector).unwrap(),
+                relative_reference.into_uri()
+            );
+        }
+    }
+
+    #[test]
+    fn from_uri() {
+        let uri = Uri::parse("../x").unwrap();
+        assert!(RelativeReference::try_from(uri).is_ok());
+        let uri = Uri::parse("mailto:bob@example.com").unwrap();
+        assert_eq!(
+            Err(Error::UnexpectedScheme),
+            RelativeReference::try_from(uri)
+        );
+    }
+}
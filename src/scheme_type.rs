@@ -0,0 +1,17 @@
+/// This classifies the scheme of a URI according to the
+/// [WHATWG URL Standard](https://url.spec.whatwg.org/#special-scheme), which
+/// gives "special" schemes, such as `http`, hierarchical semantics based on
+/// an authority, and treats the rest as opaque.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SchemeType {
+    /// The scheme is one of the special schemes: `ftp`, `file`, `http`,
+    /// `https`, `ws`, or `wss`.
+    Special,
+
+    /// The scheme is not one of the special schemes, such as `mailto` or
+    /// `urn`.
+    NotSpecial,
+
+    /// There is no scheme, as in a relative reference such as `../foo`.
+    NoScheme,
+}
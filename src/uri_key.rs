@@ -0,0 +1,143 @@
+#![warn(clippy::pedantic)]
+
+use std::convert::TryFrom;
+
+use super::{
+    error::Error,
+    uri::Uri,
+};
+
+/// This is a key for looking up URIs in a map such as a `HashMap`, made
+/// from the canonical string form of a [`Uri`], so that a key made from a
+/// `Uri` matches a key made from any string which parses into an
+/// equivalent URI.  The canonical form is the string form of the URI after
+/// it's been [normalized], which removes `.` and `..` path segments and
+/// makes the scheme lowercase; the host is lowercased, and
+/// percent-encodings made consistent, when a URI is parsed.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate rhymuri;
+/// use rhymuri::{
+///     Uri,
+///     UriKey,
+/// };
+/// use std::{
+///     collections::HashMap,
+///     convert::TryFrom,
+/// };
+///
+/// # fn main() -> Result<(), rhymuri::Error> {
+/// let mut map = HashMap::new();
+/// map.insert(UriKey::from(&Uri::parse("http://www.example.com/a")?), 42);
+/// let key = UriKey::try_from("HTTP://www.EXAMPLE.com/b/../%61")?;
+/// assert_eq!(Some(&42), map.get(&key));
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Uri`]: struct.Uri.html
+/// [normalized]: struct.Uri.html#method.normalize
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct UriKey(String);
+
+impl UriKey {
+    /// Borrow the canonical string form of the URI.
+    #[must_use = "why ask for the string if you're not going to use it?"]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for UriKey {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&Uri> for UriKey {
+    fn from(uri: &Uri) -> Self {
+        let mut uri = uri.clone();
+        uri.normalize();
+        Self(uri.to_string())
+    }
+}
+
+impl From<Uri> for UriKey {
+    fn from(mut uri: Uri) -> Self {
+        uri.normalize();
+        Self(uri.to_string())
+    }
+}
+
+impl TryFrom<&'_ str> for UriKey {
+    type Error = Error;
+
+    fn try_from(uri_string: &'_ str) -> Result<Self, Self::Error> {
+        Ok(Self::from(Uri::parse(uri_string)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::parse_options::ParseOptions;
+    use std::collections::HashMap;
+
+    #[test]
+    // NOTE: These lints are disabled because they're triggered inside the
+    // `named_tuple!` macro expansion.
+    #[allow(clippy::ref_option_ref)]
+    #[allow(clippy::from_over_into)]
+    fn lookup_by_string() {
+        let mut map = HashMap::new();
+        for (i, uri_string) in
+            ["http://www.example.com/a/b?c#d", "mailto:bob@example.com", "/x/y"]
+                .iter()
+                .enumerate()
+        {
+            map.insert(UriKey::from(Uri::parse(uri_string).unwrap()), i);
+        }
+        named_tuple!(
+            struct TestVector {
+                uri_string: &'static str,
+                value: Option<&'static usize>,
+            }
+        );
+        let test_vectors: &[TestVector] = &[
+            ("http://www.example.com/a/b?c#d", Some(&0)).into(),
+            ("HTTP://WWW.EXAMPLE.COM/a/./b?c#d", Some(&0)).into(),
+            ("http://www.example.com/a/x/../%62?c#d", Some(&0)).into(),
+            ("http://www.example.com/a/b?c", None).into(),
+            ("http://www.example.com/A/b?c#d", None).into(),
+            ("MAILTO:bob@example.com", Some(&1)).into(),
+            ("/x/z/../y", Some(&2)).into(),
+            ("x/y", None).into(),
+        ];
+        for test_vector in test_vectors {
+            let key = UriKey::try_from(*test_vector.uri_string()).unwrap();
+            assert_eq!(
+                *test_vector.value(),
+                map.get(&key),
+                "{}",
+                test_vector.uri_string()
+            );
+        }
+        assert!(UriKey::try_from("/a b").is_err());
+    }
+
+    #[test]
+    fn preserved_scheme_case() {
+        let options = ParseOptions {
+            preserve_scheme_case: true,
+            ..ParseOptions::default()
+        };
+        let uri = Uri::parse_with_options("HTTP://a/", options).unwrap();
+        assert_eq!(UriKey::try_from("http://a/").unwrap(), UriKey::from(uri));
+    }
+}
@@ -0,0 +1,130 @@
+use super::{
+    authority::Authority,
+    character_classes::{
+        PCHAR_NOT_PCT_ENCODED,
+        QUERY_NOT_PCT_ENCODED_WITHOUT_PLUS,
+        QUERY_OR_FRAGMENT_NOT_PCT_ENCODED,
+        SEGMENT_NZ_NC_NOT_PCT_ENCODED,
+    },
+    codec::{
+        encode_element,
+        encode_element_escaping,
+    },
+};
+
+/// This is a borrowed view of the components of a [`Uri`].  It can be used to
+/// format a URI without cloning or taking ownership of any of its components.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate rhymuri;
+/// use rhymuri::Uri;
+///
+/// # fn main() -> Result<(), rhymuri::Error> {
+/// let uri = Uri::parse("http://www.example.com/foo?bar#baz")?;
+/// let view = uri.as_ref_view();
+/// assert_eq!(Some("http"), view.scheme());
+/// assert_eq!("http://www.example.com/foo?bar#baz", view.to_string());
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Uri`]: struct.Uri.html
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UriRef<'a> {
+    pub(crate) scheme: Option<&'a str>,
+    pub(crate) authority: Option<&'a Authority>,
+    pub(crate) path: &'a [Vec<u8>],
+    pub(crate) query: Option<&'a [u8]>,
+    pub(crate) query_escapes: &'a [usize],
+    pub(crate) fragment: Option<&'a [u8]>,
+}
+
+impl<'a> UriRef<'a> {
+    /// Borrow the authority (if any) of the URI.
+    #[must_use = "respect mah authoritah"]
+    pub fn authority(&self) -> Option<&'a Authority> {
+        self.authority
+    }
+
+    /// Borrow the fragment (if any) of the URI.
+    #[must_use = "why borrow the fragment if you're not going to use it?"]
+    pub fn fragment(&self) -> Option<&'a [u8]> {
+        self.fragment
+    }
+
+    /// Borrow the path component of the URI.
+    ///
+    /// Note: See [`Uri::path`](struct.Uri.html#method.path) for special notes
+    /// about what the segments of the path mean.
+    #[must_use = "you borrowed the path, so why you no use?"]
+    pub fn path(&self) -> &'a [Vec<u8>] {
+        self.path
+    }
+
+    /// Borrow the query (if any) of the URI.
+    #[must_use = "don't you want to know what that query was?"]
+    pub fn query(&self) -> Option<&'a [u8]> {
+        self.query
+    }
+
+    /// Borrow the scheme (if any) component of the URI.
+    #[must_use = "you wanted to use that scheme, right?"]
+    pub fn scheme(&self) -> Option<&'a str> {
+        self.scheme
+    }
+}
+
+impl std::fmt::Display for UriRef<'_> {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        if let Some(scheme) = self.scheme {
+            write!(f, "{}:", scheme)?;
+        }
+        if let Some(authority) = self.authority {
+            write!(f, "//{}", authority)?;
+        }
+        // Special case: absolute but otherwise empty path.
+        if matches!(self.path, [segment] if segment.is_empty()) {
+            write!(f, "/")?;
+        }
+        for (i, segment) in self.path.iter().enumerate() {
+            // Special case: a colon in the first segment of a relative
+            // reference would be mistaken for a scheme delimiter.
+            let allowed_characters = if i == 0
+                && self.scheme.is_none()
+                && self.authority.is_none()
+            {
+                &SEGMENT_NZ_NC_NOT_PCT_ENCODED
+            } else {
+                &PCHAR_NOT_PCT_ENCODED
+            };
+            write!(f, "{}", encode_element(segment, allowed_characters))?;
+            if i + 1 < self.path.len() {
+                write!(f, "/")?;
+            }
+        }
+        if let Some(query) = self.query {
+            write!(
+                f,
+                "?{}",
+                encode_element_escaping(
+                    query,
+                    &QUERY_NOT_PCT_ENCODED_WITHOUT_PLUS,
+                    self.query_escapes
+                )
+            )?;
+        }
+        if let Some(fragment) = self.fragment {
+            write!(
+                f,
+                "#{}",
+                encode_element(fragment, &QUERY_OR_FRAGMENT_NOT_PCT_ENCODED)
+            )?;
+        }
+        Ok(())
+    }
+}
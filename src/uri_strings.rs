@@ -0,0 +1,24 @@
+/// This holds the components of a [`Uri`] converted to strings, as returned
+/// by [`Uri::to_strings`], for code which needs all of them as strings and
+/// would rather handle a possible conversion error only once.
+///
+/// [`Uri`]: struct.Uri.html
+/// [`Uri::to_strings`]: struct.Uri.html#method.to_strings
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UriStrings {
+    /// The scheme of the URI, if any.
+    pub scheme: Option<String>,
+
+    /// The host of the URI, if it has an authority.
+    pub host: Option<String>,
+
+    /// The path of the URI, as returned by
+    /// [`Uri::path_to_string`](struct.Uri.html#method.path_to_string).
+    pub path: String,
+
+    /// The query of the URI, if any.
+    pub query: Option<String>,
+
+    /// The fragment of the URI, if any.
+    pub fragment: Option<String>,
+}
@@ -9,6 +9,7 @@ use super::{
 struct Shared {
     num_groups: usize,
     octet_buffer: String,
+    octet_start: usize,
 }
 
 enum State {
@@ -17,27 +18,32 @@ enum State {
 }
 
 impl State {
-    fn finalize(self) -> Result<(), Error> {
+    fn finalize(
+        self,
+        end: usize,
+    ) -> Result<(), (Error, usize)> {
         match self {
-            Self::NotInOctet(_) => Err(Error::TruncatedHost),
+            Self::NotInOctet(_) => Err((Error::TruncatedHost, end)),
             Self::ExpectDigitOrDot(state) => {
                 Self::finalize_expect_digit_or_dot(state)
             },
         }
     }
 
-    fn finalize_expect_digit_or_dot(state: Shared) -> Result<(), Error> {
+    fn finalize_expect_digit_or_dot(
+        state: Shared
+    ) -> Result<(), (Error, usize)> {
         let mut state = state;
         if !state.octet_buffer.is_empty() {
             state.num_groups += 1;
             if state.octet_buffer.parse::<u8>().is_err() {
-                return Err(Error::InvalidDecimalOctet);
+                return Err((Error::InvalidDecimalOctet, state.octet_start));
             }
         }
         match state.num_groups {
             4 => Ok(()),
-            n if n < 4 => Err(Error::TooFewAddressParts),
-            _ => Err(Error::TooManyAddressParts),
+            n if n < 4 => Err((Error::TooFewAddressParts, 0)),
+            _ => Err((Error::TooManyAddressParts, 0)),
         }
     }
 
@@ -45,46 +51,51 @@ impl State {
         Self::NotInOctet(Shared {
             num_groups: 0,
             octet_buffer: String::new(),
+            octet_start: 0,
         })
     }
 
     fn next(
         self,
+        i: usize,
         c: char,
-    ) -> Result<Self, Error> {
+    ) -> Result<Self, (Error, usize)> {
         match self {
-            Self::NotInOctet(state) => Self::next_not_in_octet(state, c),
+            Self::NotInOctet(state) => Self::next_not_in_octet(state, i, c),
             Self::ExpectDigitOrDot(state) => {
-                Self::next_expect_digit_or_dot(state, c)
+                Self::next_expect_digit_or_dot(state, i, c)
             },
         }
     }
 
     fn next_not_in_octet(
         state: Shared,
+        i: usize,
         c: char,
-    ) -> Result<Self, Error> {
+    ) -> Result<Self, (Error, usize)> {
         let mut state = state;
         if DIGIT.contains(&c) {
             state.octet_buffer.push(c);
+            state.octet_start = i;
             Ok(Self::ExpectDigitOrDot(state))
         } else {
-            Err(Error::IllegalCharacter(Context::Ipv4Address))
+            Err((Error::IllegalCharacter(Context::Ipv4Address), i))
         }
     }
 
     fn next_expect_digit_or_dot(
         state: Shared,
+        i: usize,
         c: char,
-    ) -> Result<Self, Error> {
+    ) -> Result<Self, (Error, usize)> {
         let mut state = state;
         if c == '.' {
             state.num_groups += 1;
             if state.num_groups > 4 {
-                return Err(Error::TooManyAddressParts);
+                return Err((Error::TooManyAddressParts, i));
             }
             if state.octet_buffer.parse::<u8>().is_err() {
-                return Err(Error::InvalidDecimalOctet);
+                return Err((Error::InvalidDecimalOctet, state.octet_start));
             }
             state.octet_buffer.clear();
             Ok(Self::NotInOctet(state))
@@ -92,16 +103,50 @@ impl State {
             state.octet_buffer.push(c);
             Ok(Self::ExpectDigitOrDot(state))
         } else {
-            Err(Error::IllegalCharacter(Context::Ipv4Address))
+            Err((Error::IllegalCharacter(Context::Ipv4Address), i))
         }
     }
 }
 
-pub fn validate_ipv4_address<T>(address: T) -> Result<(), Error>
+// If the given string looks like an IPv4 address (it consists only of
+// decimal or "0x"-prefixed hexadecimal numbers separated by dots), check
+// that every octet is plain decimal without leading zeros, since some
+// resolvers interpret such octets as octal or hexadecimal.  Anything else is
+// accepted.
+pub fn reject_non_decimal_octets<T>(address: T) -> Result<(), Error>
+where
+    T: AsRef<str>,
+{
+    let is_decimal = |octet: &str| octet.chars().all(|c| DIGIT.contains(&c));
+    let is_hexadecimal = |octet: &str| {
+        octet.get(0..2).is_some_and(|prefix| prefix.eq_ignore_ascii_case("0x"))
+            && octet[2..].chars().all(|c| c.is_ascii_hexdigit())
+    };
+    let address = address.as_ref();
+    if address
+        .split('.')
+        .all(|octet| is_decimal(octet) || is_hexadecimal(octet))
+        && address.split('.').any(|octet| {
+            is_hexadecimal(octet) || (octet.len() > 1 && octet.starts_with('0'))
+        })
+    {
+        Err(Error::InvalidDecimalOctet)
+    } else {
+        Ok(())
+    }
+}
+
+// Check the given IPv4 address.  Any error is returned along with the byte
+// offset in the address where the problem was found.
+pub fn validate_ipv4_address<T>(address: T) -> Result<(), (Error, usize)>
 where
     T: AsRef<str>,
 {
-    address.as_ref().chars().try_fold(State::new(), State::next)?.finalize()
+    let address = address.as_ref();
+    address
+        .char_indices()
+        .try_fold(State::new(), |machine, (i, c)| machine.next(i, c))?
+        .finalize(address.len())
 }
 
 #[cfg(test)]
@@ -135,27 +180,75 @@ mod tests {
             struct TestVector {
                 address_string: &'static str,
                 expected_error: Error,
+                expected_offset: usize,
             }
         );
         let test_vectors: &[TestVector] = &[
-            ("1.2.x.4", Error::IllegalCharacter(Context::Ipv4Address)).into(),
-            ("1.2.3.4.8", Error::TooManyAddressParts).into(),
-            ("1.2.3", Error::TooFewAddressParts).into(),
-            ("1.2.3.", Error::TruncatedHost).into(),
-            ("1.2.3.256", Error::InvalidDecimalOctet).into(),
-            ("1.2.3.-4", Error::IllegalCharacter(Context::Ipv4Address)).into(),
-            ("1.2.3. 4", Error::IllegalCharacter(Context::Ipv4Address)).into(),
-            ("1.2.3.4 ", Error::IllegalCharacter(Context::Ipv4Address)).into(),
+            ("1.2.x.4", Error::IllegalCharacter(Context::Ipv4Address), 4)
+                .into(),
+            ("1.2.3.4.8", Error::TooManyAddressParts, 0).into(),
+            ("1.2.3", Error::TooFewAddressParts, 0).into(),
+            ("1.2.3.", Error::TruncatedHost, 6).into(),
+            ("1.2.3.256", Error::InvalidDecimalOctet, 6).into(),
+            ("1.2.3.-4", Error::IllegalCharacter(Context::Ipv4Address), 6)
+                .into(),
+            ("1.2.3. 4", Error::IllegalCharacter(Context::Ipv4Address), 6)
+                .into(),
+            ("1.2.3.4 ", Error::IllegalCharacter(Context::Ipv4Address), 7)
+                .into(),
         ];
         for test_vector in test_vectors {
             let result = validate_ipv4_address(test_vector.address_string());
             assert!(result.is_err(), "{}", test_vector.address_string());
             assert_eq!(
-                *test_vector.expected_error(),
+                (
+                    test_vector.expected_error().clone(),
+                    *test_vector.expected_offset()
+                ),
                 result.unwrap_err(),
                 "{}",
                 test_vector.address_string()
             );
         }
     }
+
+    #[test]
+    fn non_decimal_octets() {
+        let test_vectors = [
+            ("1.2.3.4", true),
+            ("0.0.0.0", true),
+            ("1.2.3.04", false),
+            ("01.2.3.4", false),
+            ("1.2.00.4", false),
+            ("1.2.3.40", true),
+            ("www.example.com", true),
+            ("0x7f.www.example.com", true),
+            ("", true),
+        ];
+        for (address, is_ok) in &test_vectors {
+            if *is_ok {
+                assert!(
+                    reject_non_decimal_octets(address).is_ok(),
+                    "{}",
+                    address
+                );
+            } else {
+                assert!(validate_ipv4_address(address).is_ok(), "{}", address);
+                assert_eq!(
+                    Err(Error::InvalidDecimalOctet),
+                    reject_non_decimal_octets(address),
+                    "{}",
+                    address
+                );
+            }
+        }
+        for address in &["0x7f.0.0.1", "1.2.0X3.4", "0x.0.0.1"] {
+            assert_eq!(
+                Err(Error::InvalidDecimalOctet),
+                reject_non_decimal_octets(address),
+                "{}",
+                address
+            );
+        }
+    }
 }
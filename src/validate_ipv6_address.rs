@@ -40,23 +40,25 @@ enum State<'a> {
 }
 
 impl<'a> State<'a> {
-    fn finalize(mut self) -> Result<(), Error> {
+    // The error (if any) is returned along with the byte offset in the
+    // address where the problem was found.
+    fn finalize(mut self) -> Result<(), (Error, usize)> {
         match &mut self {
             Self::InGroupNotIpv4(state) | Self::InGroupCouldBeIpv4(state) => {
                 // count trailing group
                 state.num_groups += 1;
             },
             Self::InGroupIpv4(state) => {
-                validate_ipv4_address(
-                    &state.address[state.potential_ipv4_address_start..],
-                )?;
+                let ipv4_address_start = state.potential_ipv4_address_start;
+                validate_ipv4_address(&state.address[ipv4_address_start..])
+                    .map_err(|(error, i)| (error, ipv4_address_start + i))?;
                 state.num_groups += 2;
             },
             _ => {},
         };
         match self {
-            Self::ColonButNoGroupsYet(_) | Self::ColonAfterGroup(_) => {
-                Err(Error::TruncatedHost)
+            Self::ColonButNoGroupsYet(state) | Self::ColonAfterGroup(state) => {
+                Err((Error::TruncatedHost, state.address.len()))
             },
 
             Self::AfterDoubleColon(state)
@@ -67,8 +69,8 @@ impl<'a> State<'a> {
                 match (state.double_colon_encountered, state.num_groups) {
                     (true, n) if n <= 7 => Ok(()),
                     (false, 8) => Ok(()),
-                    (false, n) if n < 8 => Err(Error::TooFewAddressParts),
-                    (_, _) => Err(Error::TooManyAddressParts),
+                    (false, n) if n < 8 => Err((Error::TooFewAddressParts, 0)),
+                    (_, _) => Err((Error::TooManyAddressParts, 0)),
                 }
             },
         }
@@ -235,18 +237,32 @@ impl<'a> State<'a> {
 }
 
 pub fn validate_ipv6_address<T>(address: T) -> Result<(), Error>
+where
+    T: AsRef<str>,
+{
+    validate_ipv6_address_noting_offset(address).map_err(|(error, _)| error)
+}
+
+// This is the same as `validate_ipv6_address`, except that the error is
+// returned along with the byte offset in the address where the problem was
+// found.
+pub fn validate_ipv6_address_noting_offset<T>(
+    address: T
+) -> Result<(), (Error, usize)>
 where
     T: AsRef<str>,
 {
     let address = address.as_ref();
     address
         .char_indices()
-        .try_fold(State::new(address), |machine, (i, c)| machine.next(i, c))
-        .or_else(|machine_exit_status| match machine_exit_status {
+        .try_fold(State::new(address), |machine, (i, c)| {
+            machine.next(i, c).map_err(|status| (status, i))
+        })
+        .or_else(|(machine_exit_status, i)| match machine_exit_status {
             MachineExitStatus::Ipv4Trailer(state) => {
                 Ok(State::InGroupIpv4(state))
             },
-            MachineExitStatus::Error(error) => Err(error),
+            MachineExitStatus::Error(error) => Err((error, i)),
         })?
         .finalize()
 }